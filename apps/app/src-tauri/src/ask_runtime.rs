@@ -16,6 +16,7 @@ const ASK_SOCKET_PATH_SEGMENTS: [&str; 2] = [".coda", "runtime"];
 const ASK_SOCKET_SWEEP_INTERVAL: Duration = Duration::from_secs(5);
 const ASK_EXPIRED_RETENTION_WINDOW: TimeDuration = TimeDuration::seconds(30);
 const ASK_RESPONSE_SOURCE: &str = "tauri-ui";
+const ASK_REQUEST_TYPE: &str = "ask_request";
 pub const ASK_SESSION_CREATED_EVENT: &str = "ask_session_created";
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -52,6 +53,8 @@ struct AskSocketRequest {
     request: AskRequestBatch,
     timeout_ms: u64,
     requested_at_iso: String,
+    #[serde(default)]
+    linked_doc_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -72,7 +75,7 @@ enum AskResponseStatus {
 }
 
 #[derive(Debug, Clone, Serialize)]
-struct AskResponseBatch {
+pub struct AskResponseBatch {
     ask_id: String,
     answers: Vec<AskAnswer>,
     note: Option<String>,
@@ -88,6 +91,7 @@ struct PendingAskSession {
     requested_at: OffsetDateTime,
     requested_at_iso: String,
     timeout_ms: u64,
+    linked_doc_id: Option<String>,
     response_sender: mpsc::Sender<AskResponseBatch>,
 }
 
@@ -110,6 +114,7 @@ pub struct PendingAskSessionView {
     timeout_ms: u64,
     expires_at_iso: Option<String>,
     is_expired: bool,
+    linked_doc_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -126,6 +131,7 @@ pub struct AskSessionCreatedEventPayload {
     ask_id: String,
     requested_at_iso: String,
     first_question_text: Option<String>,
+    linked_doc_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize, PartialEq, Eq)]
@@ -135,6 +141,69 @@ enum SubmitAskResponseStatus {
     Cancelled,
 }
 
+impl AskOption {
+    pub fn new(label: &str, description: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            description: description.to_string(),
+        }
+    }
+}
+
+impl AskQuestion {
+    pub fn new(header: &str, id: &str, question: &str, options: Vec<AskOption>) -> Self {
+        Self {
+            header: header.to_string(),
+            id: id.to_string(),
+            question: question.to_string(),
+            options,
+        }
+    }
+}
+
+impl AskNote {
+    pub fn new(label: &str, required: bool) -> Self {
+        Self {
+            label: label.to_string(),
+            required,
+        }
+    }
+}
+
+impl AskRequestBatch {
+    pub fn new(questions: Vec<AskQuestion>, note: Option<AskNote>) -> Self {
+        Self { questions, note }
+    }
+}
+
+impl AskAnswer {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected_index
+    }
+
+    pub fn other_text(&self) -> Option<&str> {
+        self.other_text.as_deref()
+    }
+}
+
+impl AskResponseBatch {
+    pub fn is_answered(&self) -> bool {
+        self.status == AskResponseStatus::Answered
+    }
+
+    pub fn answers(&self) -> &[AskAnswer] {
+        &self.answers
+    }
+
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+}
+
 impl AskRuntimeState {
     pub fn new() -> Self {
         Self {
@@ -157,6 +226,7 @@ impl AskRuntimeState {
             requested_at,
             requested_at_iso: request.requested_at_iso,
             timeout_ms: request.timeout_ms,
+            linked_doc_id: request.linked_doc_id,
             response_sender,
         };
 
@@ -177,12 +247,41 @@ impl AskRuntimeState {
                 .questions
                 .first()
                 .map(|question| question.question.clone()),
+            linked_doc_id: session.linked_doc_id.clone(),
         };
 
         inner.pending.insert(ask_id, session);
         Ok(event_payload)
     }
 
+    pub fn open_app_session(
+        &self,
+        app_handle: &AppHandle,
+        ask_id: String,
+        linked_doc_id: Option<String>,
+        request: AskRequestBatch,
+        timeout_ms: u64,
+    ) -> Result<mpsc::Receiver<AskResponseBatch>, String> {
+        let (response_sender, response_receiver) = mpsc::channel::<AskResponseBatch>();
+        let created_payload = self.insert_pending_session(
+            AskSocketRequest {
+                request_type: ASK_REQUEST_TYPE.to_string(),
+                ask_id,
+                request,
+                timeout_ms,
+                requested_at_iso: now_iso_utc(),
+                linked_doc_id,
+            },
+            response_sender,
+        )?;
+
+        if let Err(error) = emit_ask_session_created_event(app_handle, &created_payload) {
+            log::warn!("{error}");
+        }
+
+        Ok(response_receiver)
+    }
+
     fn remove_pending_session(&self, ask_id: &str) {
         if let Ok(mut inner) = self.inner.lock() {
             inner.pending.remove(ask_id);
@@ -209,6 +308,7 @@ impl AskRuntimeState {
                     timeout_ms: session.timeout_ms,
                     expires_at_iso: session_expiry_iso(session),
                     is_expired: is_session_expired(session, now),
+                    linked_doc_id: session.linked_doc_id.clone(),
                 })
                 .collect::<Vec<PendingAskSessionView>>()
         };
@@ -454,7 +554,7 @@ fn read_socket_request(stream: &UnixStream) -> Result<AskSocketRequest, Error> {
 }
 
 fn validate_socket_request(request: &AskSocketRequest) -> Result<(), Error> {
-    if request.request_type != "ask_request" {
+    if request.request_type != ASK_REQUEST_TYPE {
         return Err(Error::other("unsupported ask socket request type"));
     }
 
@@ -35,6 +35,7 @@ fn build_request(timeout_ms: u64, requested_at_iso: &str, require_note: bool) ->
         },
         timeout_ms,
         requested_at_iso: requested_at_iso.to_string(),
+        linked_doc_id: None,
     }
 }
 
@@ -53,6 +54,7 @@ fn returns_created_event_payload_when_session_is_inserted() {
             ask_id: "ask-test-1".to_string(),
             requested_at_iso: "2026-02-19T00:00:00Z".to_string(),
             first_question_text: Some("Choose scope".to_string()),
+            linked_doc_id: None,
         }
     );
 }
@@ -0,0 +1,133 @@
+use crate::plan_viewer::{content_revision, split_frontmatter};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocContentHash {
+    frontmatter: String,
+    body: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DocContentChange {
+    pub doc_id: String,
+    pub frontmatter_changed: bool,
+    pub body_changed: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct DocContentHashes {
+    hashes: HashMap<String, DocContentHash>,
+}
+
+impl DocContentHash {
+    pub fn read(doc_path: &Path) -> Option<Self> {
+        fs::read_to_string(doc_path)
+            .ok()
+            .map(|contents| Self::from_contents(&contents, doc_path))
+    }
+
+    pub fn from_contents(contents: &str, doc_path: &Path) -> Self {
+        match split_frontmatter(contents, doc_path) {
+            Ok((_, frontmatter, body)) => Self {
+                frontmatter: content_revision(&frontmatter),
+                body: content_revision(&body),
+            },
+            Err(_) => Self {
+                frontmatter: String::new(),
+                body: content_revision(contents),
+            },
+        }
+    }
+}
+
+impl DocContentHashes {
+    pub fn insert(&mut self, doc_id: String, hash: DocContentHash) {
+        self.hashes.insert(doc_id, hash);
+    }
+
+    pub fn remove(&mut self, doc_id: &str) {
+        self.hashes.remove(doc_id);
+    }
+
+    pub fn doc_ids(&self) -> impl Iterator<Item = &str> {
+        self.hashes.keys().map(String::as_str)
+    }
+
+    pub fn record(
+        &mut self,
+        doc_id: &str,
+        hash: Option<DocContentHash>,
+    ) -> Option<DocContentChange> {
+        let Some(hash) = hash else {
+            self.hashes.remove(doc_id);
+            return Some(DocContentChange {
+                doc_id: doc_id.to_string(),
+                frontmatter_changed: true,
+                body_changed: true,
+            });
+        };
+
+        let change = match self.hashes.get(doc_id) {
+            Some(previous) if *previous == hash => None,
+            Some(previous) => Some(DocContentChange {
+                doc_id: doc_id.to_string(),
+                frontmatter_changed: previous.frontmatter != hash.frontmatter,
+                body_changed: previous.body != hash.body,
+            }),
+            None => Some(DocContentChange {
+                doc_id: doc_id.to_string(),
+                frontmatter_changed: true,
+                body_changed: true,
+            }),
+        };
+
+        self.hashes.insert(doc_id.to_string(), hash);
+        change
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DocContentChange, DocContentHash, DocContentHashes};
+    use std::path::Path;
+
+    fn hash(contents: &str) -> Option<DocContentHash> {
+        Some(DocContentHash::from_contents(contents, Path::new("PRD.md")))
+    }
+
+    #[test]
+    fn reports_which_part_of_a_doc_changed() {
+        let mut hashes = DocContentHashes::default();
+        let original = "---\ntitle: PRD\n---\n\nBody\n";
+
+        assert_eq!(
+            hashes.record("PRD.md", hash(original)),
+            Some(DocContentChange {
+                doc_id: "PRD.md".to_string(),
+                frontmatter_changed: true,
+                body_changed: true,
+            })
+        );
+        assert_eq!(hashes.record("PRD.md", hash(original)), None);
+        assert_eq!(
+            hashes.record("PRD.md", hash("---\ntitle: PRD\n---\n\nNew body\n")),
+            Some(DocContentChange {
+                doc_id: "PRD.md".to_string(),
+                frontmatter_changed: false,
+                body_changed: true,
+            })
+        );
+        assert_eq!(
+            hashes.record("PRD.md", hash("---\ntitle: Renamed\n---\n\nNew body\n")),
+            Some(DocContentChange {
+                doc_id: "PRD.md".to_string(),
+                frontmatter_changed: true,
+                body_changed: false,
+            })
+        );
+    }
+}
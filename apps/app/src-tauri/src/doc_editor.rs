@@ -0,0 +1,312 @@
+use crate::doc_format::FrontmatterSyntax;
+use crate::doc_merge::merge_text;
+use crate::plan_viewer::{
+    compose_doc_contents, content_revision, get_doc_document_from_root, resolve_canonical_roots,
+    resolve_existing_doc_path, split_frontmatter, validate_doc_contents, validate_doc_id,
+    write_doc_file_atomic, DocDocument,
+};
+use crate::project_runtime::ProjectRegistryState;
+use serde::Serialize;
+use serde_yaml::Mapping;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocBodyMerge {
+    pub doc_id: String,
+    pub revision: String,
+    pub merged_body: String,
+    pub has_conflicts: bool,
+}
+
+#[tauri::command]
+pub fn create_doc(
+    doc_id: String,
+    frontmatter: Option<Mapping>,
+    body: Option<String>,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<DocDocument, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&doc_id)?;
+    create_doc_from_root(
+        &active_project.root_path,
+        &docs_root.path,
+        &doc_id,
+        frontmatter.unwrap_or_default(),
+        body.as_deref().unwrap_or_default(),
+    )
+    .map(|document| document.scoped(docs_root))
+}
+
+#[tauri::command]
+pub fn update_doc_body(
+    doc_id: String,
+    base_revision: String,
+    body: String,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<DocDocument, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&doc_id)?;
+    update_doc_body_from_root(
+        &active_project.root_path,
+        &docs_root.path,
+        &doc_id,
+        &base_revision,
+        &body,
+    )
+    .map(|document| document.scoped(docs_root))
+}
+
+#[tauri::command]
+pub fn update_doc_frontmatter(
+    doc_id: String,
+    base_revision: String,
+    updates: Mapping,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<DocDocument, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&doc_id)?;
+    update_doc_frontmatter_from_root(
+        &active_project.root_path,
+        &docs_root.path,
+        &doc_id,
+        &base_revision,
+        updates,
+    )
+    .map(|document| document.scoped(docs_root))
+}
+
+#[tauri::command]
+pub fn merge_doc_body(
+    doc_id: String,
+    base_body: String,
+    body: String,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<DocBodyMerge, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&doc_id)?;
+    merge_doc_body_from_root(
+        &active_project.root_path,
+        &docs_root.path,
+        &doc_id,
+        &base_body,
+        &body,
+    )
+    .map(|merge| DocBodyMerge {
+        doc_id: docs_root.scoped_doc_id(&merge.doc_id),
+        ..merge
+    })
+}
+
+pub fn create_doc_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    frontmatter: Mapping,
+    body: &str,
+) -> Result<DocDocument, String> {
+    validate_doc_id(doc_id).map_err(|error| format!("document creation failed: {error}"))?;
+
+    let doc_id = doc_id.trim();
+    let (_, docs_root_canonical) = resolve_canonical_roots(workspace_root, docs_root)?;
+    let doc_path = docs_root_canonical.join(doc_id);
+
+    if doc_path.exists() {
+        return Err(format!(
+            "document creation failed: document already exists: {doc_id}"
+        ));
+    }
+
+    ensure_within_docs_root(&doc_path, &docs_root_canonical)?;
+
+    if let Some(parent) = doc_path.parent() {
+        fs::create_dir_all(parent).map_err(|error| {
+            format!(
+                "document creation failed: cannot create directory {}: {error}",
+                parent.display()
+            )
+        })?;
+    }
+
+    let syntax = FrontmatterSyntax::default();
+    let contents = compose_doc_contents(syntax, &syntax.serialize_mapping(&frontmatter)?, body);
+    validate_doc_contents(&contents, &doc_path)
+        .map_err(|error| format!("document creation failed: {error}"))?;
+    write_new_doc_file(&doc_path, doc_id, &contents)?;
+
+    get_doc_document_from_root(workspace_root, docs_root, doc_id)
+}
+
+pub fn update_doc_body_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    base_revision: &str,
+    body: &str,
+) -> Result<DocDocument, String> {
+    let (doc_path, contents) = read_existing_doc(workspace_root, docs_root, doc_id)?;
+    ensure_doc_revision(doc_id, &contents, base_revision)?;
+    let (syntax, frontmatter, _) = split_frontmatter(&contents, &doc_path)?;
+    let next_contents = compose_doc_contents(syntax, &frontmatter, &body.replace("\r\n", "\n"));
+
+    write_doc_file_atomic(
+        &doc_path,
+        &with_original_line_endings(&contents, next_contents),
+    )?;
+    get_doc_document_from_root(workspace_root, docs_root, doc_id)
+}
+
+pub fn update_doc_frontmatter_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    base_revision: &str,
+    updates: Mapping,
+) -> Result<DocDocument, String> {
+    let (doc_path, contents) = read_existing_doc(workspace_root, docs_root, doc_id)?;
+    ensure_doc_revision(doc_id, &contents, base_revision)?;
+    let (syntax, frontmatter, body) = split_frontmatter(&contents, &doc_path)?;
+
+    let mut fields = syntax
+        .parse_mapping(&frontmatter, &doc_path)
+        .map_err(|error| format!("document update failed: {error}"))?;
+
+    for (key, value) in &updates {
+        if value.is_null() {
+            fields.remove(key);
+        } else {
+            fields.insert(key.clone(), value.clone());
+        }
+    }
+
+    let next_frontmatter = match syntax.splice_fields(&frontmatter, &updates) {
+        Some(spliced)
+            if syntax
+                .parse_mapping(&spliced, &doc_path)
+                .is_ok_and(|spliced_fields| spliced_fields == fields) =>
+        {
+            spliced
+        }
+        _ => syntax.serialize_mapping(&fields)?,
+    };
+    let next_contents = compose_doc_contents(syntax, &next_frontmatter, &body);
+    validate_doc_contents(&next_contents, &doc_path)
+        .map_err(|error| format!("document update failed: {error}"))?;
+    write_doc_file_atomic(
+        &doc_path,
+        &with_original_line_endings(&contents, next_contents),
+    )?;
+
+    get_doc_document_from_root(workspace_root, docs_root, doc_id)
+}
+
+pub fn merge_doc_body_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    base_body: &str,
+    body: &str,
+) -> Result<DocBodyMerge, String> {
+    let current = get_doc_document_from_root(workspace_root, docs_root, doc_id)?;
+    let merge = merge_text(base_body, body, &current.markdown_body);
+
+    Ok(DocBodyMerge {
+        doc_id: current.id,
+        revision: current.revision,
+        merged_body: merge.text,
+        has_conflicts: merge.has_conflicts,
+    })
+}
+
+pub fn ensure_doc_revision(
+    doc_id: &str,
+    contents: &str,
+    base_revision: &str,
+) -> Result<(), String> {
+    let current_revision = content_revision(contents);
+    if current_revision != base_revision.trim() {
+        return Err(format!(
+            "document conflict: {doc_id} changed since revision {base_revision}; current revision is {current_revision}"
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn read_existing_doc(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+) -> Result<(PathBuf, String), String> {
+    let (_, docs_root_canonical) = resolve_canonical_roots(workspace_root, docs_root)?;
+    let doc_path = resolve_existing_doc_path(&docs_root_canonical, doc_id)?;
+    let contents = fs::read_to_string(&doc_path).map_err(|error| {
+        format!(
+            "failed to read document file {}: {error}",
+            doc_path.display()
+        )
+    })?;
+
+    Ok((doc_path, contents))
+}
+
+fn write_new_doc_file(doc_path: &Path, doc_id: &str, contents: &str) -> Result<(), String> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(doc_path)
+        .map_err(|error| match error.kind() {
+            ErrorKind::AlreadyExists => {
+                format!("document creation failed: document already exists: {doc_id}")
+            }
+            _ => format!(
+                "document creation failed: cannot create {}: {error}",
+                doc_path.display()
+            ),
+        })?;
+
+    file.write_all(contents.as_bytes()).map_err(|error| {
+        let _ = fs::remove_file(doc_path);
+        format!(
+            "document creation failed: cannot write {}: {error}",
+            doc_path.display()
+        )
+    })
+}
+
+fn with_original_line_endings(original_contents: &str, contents: String) -> String {
+    if original_contents.contains("\r\n") {
+        contents.replace('\n', "\r\n")
+    } else {
+        contents
+    }
+}
+
+fn ensure_within_docs_root(doc_path: &Path, docs_root_canonical: &Path) -> Result<(), String> {
+    let existing_ancestor = doc_path
+        .ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.exists())
+        .ok_or_else(|| "document creation failed: no existing parent directory".to_string())?;
+    let ancestor_canonical = existing_ancestor.canonicalize().map_err(|error| {
+        format!(
+            "document creation failed: cannot resolve directory {}: {error}",
+            existing_ancestor.display()
+        )
+    })?;
+
+    if !ancestor_canonical.starts_with(docs_root_canonical) {
+        return Err(
+            "document creation failed: document path is outside allowed docs directory".to_string(),
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "doc_editor_tests.rs"]
+mod tests;
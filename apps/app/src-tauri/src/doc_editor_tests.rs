@@ -0,0 +1,232 @@
+use super::{
+    create_doc_from_root, merge_doc_body_from_root, update_doc_body_from_root,
+    update_doc_frontmatter_from_root,
+};
+use crate::plan_viewer::content_revision;
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
+use serde_yaml::Mapping;
+use std::fs;
+
+fn yaml_mapping(source: &str) -> Mapping {
+    serde_yaml::from_str(source).expect("test mapping should parse")
+}
+
+#[test]
+fn creates_doc_and_rejects_existing_or_escaping_ids() {
+    let workspace = create_temp_workspace("doc-editor");
+    let docs_root = workspace.join("docs");
+
+    let document = create_doc_from_root(
+        &workspace,
+        &docs_root,
+        "brainstorms/2026-02-19-editing.md",
+        yaml_mapping("title: Editing\ndate: 2026-02-19"),
+        "## Idea\n",
+    )
+    .expect("new doc should be created");
+
+    assert_eq!(document.id, "brainstorms/2026-02-19-editing.md");
+    assert_eq!(document.title.as_deref(), Some("Editing"));
+    assert_eq!(
+        fs::read_to_string(docs_root.join("brainstorms/2026-02-19-editing.md"))
+            .expect("created doc should be readable"),
+        "---\ntitle: Editing\ndate: 2026-02-19\n---\n## Idea\n"
+    );
+
+    let duplicate_error = create_doc_from_root(
+        &workspace,
+        &docs_root,
+        "brainstorms/2026-02-19-editing.md",
+        Mapping::new(),
+        "",
+    )
+    .expect_err("existing doc should not be overwritten");
+    assert!(duplicate_error.contains("already exists"));
+
+    let escape_error =
+        create_doc_from_root(&workspace, &docs_root, "../outside.md", Mapping::new(), "")
+            .expect_err("escaping doc id should be rejected");
+    assert!(escape_error.contains("invalid path characters"));
+    assert!(!workspace.join("outside.md").exists());
+
+    let untitled = create_doc_from_root(&workspace, &docs_root, "notes.md", Mapping::new(), "")
+        .expect("doc without frontmatter fields should be created");
+    assert_eq!(untitled.display_title, "notes");
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn updates_body_and_keeps_frontmatter_text() {
+    let workspace = create_temp_workspace("doc-editor");
+    let docs_root = workspace.join("docs");
+    let doc_path = docs_root.join("solutions/fix.md");
+    let original = "---\ntitle: Fix\n# reviewer note\nowner: ops\n---\n\nTypo hre.\n";
+    write_doc_file(&doc_path, original);
+
+    let document = update_doc_body_from_root(
+        &workspace,
+        &docs_root,
+        "solutions/fix.md",
+        &content_revision(original),
+        "\nTypo here.\n",
+    )
+    .expect("body should update");
+
+    assert_eq!(document.markdown_body, "\nTypo here.\n");
+    assert_eq!(
+        fs::read_to_string(&doc_path).expect("doc should be readable"),
+        "---\ntitle: Fix\n# reviewer note\nowner: ops\n---\n\nTypo here.\n"
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn updates_frontmatter_and_preserves_unknown_keys() {
+    let workspace = create_temp_workspace("doc-editor");
+    let docs_root = workspace.join("docs");
+    let doc_path = docs_root.join("plans/active/plan.md");
+    let original = "---\ntitle: Plan\nstatus: draft\nowner: ops\nmilestone: M1\nreviewers:\n  - alice\n---\n\n## Goal\n";
+    write_doc_file(&doc_path, original);
+
+    let document = update_doc_frontmatter_from_root(
+        &workspace,
+        &docs_root,
+        "plans/active/plan.md",
+        &content_revision(original),
+        yaml_mapping("status: active\nmilestone: null\ntags: [editor]"),
+    )
+    .expect("frontmatter should update");
+
+    assert_eq!(document.status.as_deref(), Some("active"));
+    assert_eq!(document.milestone, None);
+    assert_eq!(document.tags, vec!["editor"]);
+    assert_eq!(
+        fs::read_to_string(&doc_path).expect("doc should be readable"),
+        "---\ntitle: Plan\nstatus: active\nowner: ops\nreviewers:\n  - alice\ntags:\n- editor\n---\n\n## Goal\n"
+    );
+
+    let invalid_error = update_doc_frontmatter_from_root(
+        &workspace,
+        &docs_root,
+        "plans/active/plan.md",
+        &document.revision,
+        yaml_mapping("tags: not-a-list"),
+    )
+    .expect_err("frontmatter that no longer parses should be rejected");
+    assert!(invalid_error.contains("document update failed"));
+    assert!(fs::read_to_string(&doc_path)
+        .expect("doc should be readable")
+        .contains("status: active"));
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn splices_frontmatter_edits_and_keeps_comments_dates_and_line_endings() {
+    let workspace = create_temp_workspace("doc-editor");
+    let docs_root = workspace.join("docs");
+    let yaml_path = docs_root.join("solutions/crlf.md");
+    let yaml_original =
+        "---\r\ntitle: Fix\r\n# reviewer note\r\nstatus: draft # pending\r\nowner: ops\r\n---\r\n\r\nBody\r\n";
+    write_doc_file(&yaml_path, yaml_original);
+
+    let document = update_doc_frontmatter_from_root(
+        &workspace,
+        &docs_root,
+        "solutions/crlf.md",
+        &content_revision(yaml_original),
+        yaml_mapping("status: active\nowner: null"),
+    )
+    .expect("yaml frontmatter should update");
+    assert_eq!(
+        fs::read_to_string(&yaml_path).expect("doc should be readable"),
+        "---\r\ntitle: Fix\r\n# reviewer note\r\nstatus: active\r\n---\r\n\r\nBody\r\n"
+    );
+
+    update_doc_body_from_root(
+        &workspace,
+        &docs_root,
+        "solutions/crlf.md",
+        &document.revision,
+        "\nNew body\n",
+    )
+    .expect("body should update");
+    assert_eq!(
+        fs::read_to_string(&yaml_path).expect("doc should be readable"),
+        "---\r\ntitle: Fix\r\n# reviewer note\r\nstatus: active\r\n---\r\n\r\nNew body\r\n"
+    );
+
+    let toml_path = docs_root.join("solutions/toml.md");
+    let toml_original =
+        "+++\ntitle = \"Fix\"\ndate = 2026-02-19\n# owner below\nowner = \"ops\"\n\n[extra]\nsource = \"chat\"\n+++\n\nBody\n";
+    write_doc_file(&toml_path, toml_original);
+
+    update_doc_frontmatter_from_root(
+        &workspace,
+        &docs_root,
+        "solutions/toml.md",
+        &content_revision(toml_original),
+        yaml_mapping("title: Fixed\nstatus: active"),
+    )
+    .expect("toml frontmatter should update");
+    assert_eq!(
+        fs::read_to_string(&toml_path).expect("doc should be readable"),
+        "+++\ntitle = \"Fixed\"\ndate = 2026-02-19\n# owner below\nowner = \"ops\"\nstatus = \"active\"\n\n[extra]\nsource = \"chat\"\n+++\n\nBody\n"
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn rejects_stale_writes_and_offers_body_merge() {
+    let workspace = create_temp_workspace("doc-editor");
+    let docs_root = workspace.join("docs");
+    let doc_path = docs_root.join("solutions/shared.md");
+    let original = "---\ntitle: Shared\n---\n\nIntro.\n\n- one\n";
+    write_doc_file(&doc_path, original);
+    let base_revision = content_revision(original);
+
+    let agent_edit = "---\ntitle: Shared\n---\n\nIntro.\n\n- one\n- two\n";
+    write_doc_file(&doc_path, agent_edit);
+
+    let conflict_error = update_doc_body_from_root(
+        &workspace,
+        &docs_root,
+        "solutions/shared.md",
+        &base_revision,
+        "\nIntro, edited.\n\n- one\n",
+    )
+    .expect_err("stale revision should be rejected");
+    assert!(conflict_error.contains("document conflict"));
+    assert!(conflict_error.contains(&content_revision(agent_edit)));
+    assert_eq!(
+        fs::read_to_string(&doc_path).expect("doc should be readable"),
+        agent_edit
+    );
+
+    let merge = merge_doc_body_from_root(
+        &workspace,
+        &docs_root,
+        "solutions/shared.md",
+        "\nIntro.\n\n- one\n",
+        "\nIntro, edited.\n\n- one\n",
+    )
+    .expect("merge should be computed");
+    assert!(!merge.has_conflicts);
+    assert_eq!(merge.revision, content_revision(agent_edit));
+    assert_eq!(merge.merged_body, "\nIntro, edited.\n\n- one\n- two\n");
+
+    let document = update_doc_body_from_root(
+        &workspace,
+        &docs_root,
+        "solutions/shared.md",
+        &merge.revision,
+        &merge.merged_body,
+    )
+    .expect("merged body should save against current revision");
+    assert_eq!(document.markdown_body, merge.merged_body);
+
+    teardown_workspace(&workspace);
+}
@@ -0,0 +1,380 @@
+use serde_yaml::{Mapping, Number, Value};
+use std::borrow::Cow;
+use std::ops::Range;
+use std::path::Path;
+
+const MDX_ESM_PREFIXES: [&str; 2] = ["import ", "export "];
+const CODE_FENCE_MARKERS: [&str; 2] = ["```", "~~~"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodySyntax {
+    Markdown,
+    Mdx,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DocFormat {
+    pub extension: &'static str,
+    pub body_syntax: BodySyntax,
+}
+
+pub const DOC_FORMATS: [DocFormat; 3] = [
+    DocFormat {
+        extension: "md",
+        body_syntax: BodySyntax::Markdown,
+    },
+    DocFormat {
+        extension: "markdown",
+        body_syntax: BodySyntax::Markdown,
+    },
+    DocFormat {
+        extension: "mdx",
+        body_syntax: BodySyntax::Mdx,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrontmatterSyntax {
+    #[default]
+    Yaml,
+    Toml,
+}
+
+pub fn doc_format_for_path(path: &Path) -> Option<DocFormat> {
+    let extension = path.extension()?.to_str()?;
+    DOC_FORMATS
+        .into_iter()
+        .find(|format| format.extension == extension)
+}
+
+pub fn has_doc_extension(file_name: &str) -> bool {
+    doc_format_for_path(Path::new(file_name)).is_some()
+}
+
+pub fn strip_doc_extension(file_name: &str) -> &str {
+    match doc_format_for_path(Path::new(file_name)) {
+        Some(format) => &file_name[..file_name.len() - format.extension.len() - 1],
+        None => file_name,
+    }
+}
+
+pub fn supported_doc_extensions() -> String {
+    DOC_FORMATS
+        .iter()
+        .map(|format| format!(".{}", format.extension))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+impl BodySyntax {
+    pub fn markdown_source<'a>(self, body: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::Markdown => Cow::Borrowed(body),
+            Self::Mdx => Cow::Owned(mask_mdx_esm_lines(body)),
+        }
+    }
+}
+
+impl FrontmatterSyntax {
+    const ALL: [Self; 2] = [Self::Yaml, Self::Toml];
+
+    pub fn delimiter(self) -> &'static str {
+        match self {
+            Self::Yaml => "---",
+            Self::Toml => "+++",
+        }
+    }
+
+    pub fn detect(contents: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|syntax| {
+            contents
+                .strip_prefix(syntax.delimiter())
+                .is_some_and(|remainder| remainder.starts_with('\n'))
+        })
+    }
+
+    pub fn parse_mapping(self, frontmatter: &str, path: &Path) -> Result<Mapping, String> {
+        if frontmatter.trim().is_empty() {
+            return Ok(Mapping::new());
+        }
+
+        let parse_error = |error: String| {
+            format!(
+                "failed to parse frontmatter for {}: {error}",
+                path.display()
+            )
+        };
+
+        match self {
+            Self::Yaml => serde_yaml::from_str::<Mapping>(frontmatter)
+                .map_err(|error| parse_error(error.to_string())),
+            Self::Toml => toml::from_str::<toml::Table>(frontmatter)
+                .map(toml_table_to_mapping)
+                .map_err(|error| parse_error(error.to_string())),
+        }
+    }
+
+    pub fn splice_fields(self, frontmatter: &str, updates: &Mapping) -> Option<String> {
+        let mut lines = frontmatter
+            .lines()
+            .map(str::to_string)
+            .collect::<Vec<String>>();
+
+        for (key, value) in updates {
+            let key = key.as_str()?;
+            let replacement = if value.is_null() {
+                Vec::new()
+            } else {
+                let mut field = Mapping::new();
+                field.insert(Value::from(key), value.clone());
+                self.serialize_mapping(&field)
+                    .ok()?
+                    .lines()
+                    .map(str::to_string)
+                    .collect()
+            };
+
+            let span = self.field_span(&lines, key).unwrap_or_else(|| {
+                let insert_at = self.field_insert_index(&lines);
+                insert_at..insert_at
+            });
+            lines.splice(span, replacement);
+        }
+
+        Some(lines.join("\n"))
+    }
+
+    pub fn serialize_mapping(self, fields: &Mapping) -> Result<String, String> {
+        if fields.is_empty() {
+            return Ok(String::new());
+        }
+
+        let serialized = match self {
+            Self::Yaml => serde_yaml::to_string(&Value::Mapping(fields.clone()))
+                .map_err(|error| error.to_string()),
+            Self::Toml => {
+                toml::to_string(&mapping_to_toml_table(fields)).map_err(|error| error.to_string())
+            }
+        };
+
+        serialized
+            .map(|serialized| serialized.trim_end().to_string())
+            .map_err(|error| format!("failed to serialize document frontmatter: {error}"))
+    }
+
+    fn field_span(self, lines: &[String], key: &str) -> Option<Range<usize>> {
+        let start = lines[..self.field_insert_index(lines)]
+            .iter()
+            .position(|line| self.starts_field(line, key))?;
+        let mut end = start + 1;
+        while lines
+            .get(end)
+            .is_some_and(|line| self.continues_field(line))
+        {
+            end += 1;
+        }
+        while end > start + 1 && lines[end - 1].trim().is_empty() {
+            end -= 1;
+        }
+
+        Some(start..end)
+    }
+
+    fn field_insert_index(self, lines: &[String]) -> usize {
+        match self {
+            Self::Yaml => lines.len(),
+            Self::Toml => {
+                let table_start = lines
+                    .iter()
+                    .position(|line| line.starts_with('['))
+                    .unwrap_or(lines.len());
+                lines[..table_start]
+                    .iter()
+                    .rposition(|line| !line.trim().is_empty())
+                    .map_or(0, |last_field| last_field + 1)
+            }
+        }
+    }
+
+    fn starts_field(self, line: &str, key: &str) -> bool {
+        let separator = match self {
+            Self::Yaml => ':',
+            Self::Toml => '=',
+        };
+        if line.starts_with(char::is_whitespace) {
+            return false;
+        }
+
+        line.split_once(separator).is_some_and(|(line_key, _)| {
+            let line_key = line_key.trim();
+            ['"', '\'']
+                .iter()
+                .find_map(|quote| line_key.strip_prefix(*quote)?.strip_suffix(*quote))
+                .unwrap_or(line_key)
+                == key
+        })
+    }
+
+    fn continues_field(self, line: &str) -> bool {
+        let continuation_prefix = match self {
+            Self::Yaml => '-',
+            Self::Toml => ']',
+        };
+
+        line.trim().is_empty()
+            || line.starts_with(char::is_whitespace)
+            || line.starts_with(continuation_prefix)
+    }
+}
+
+fn mask_mdx_esm_lines(body: &str) -> String {
+    let mut in_code_fence = false;
+
+    body.split_inclusive('\n')
+        .map(|line| {
+            let trimmed = line.trim_start();
+            if CODE_FENCE_MARKERS
+                .iter()
+                .any(|marker| trimmed.starts_with(marker))
+            {
+                in_code_fence = !in_code_fence;
+            }
+
+            let is_esm = !in_code_fence
+                && MDX_ESM_PREFIXES
+                    .iter()
+                    .any(|prefix| line.starts_with(prefix));
+            if !is_esm {
+                return line.to_string();
+            }
+
+            let content = line.trim_end_matches('\n');
+            format!("{}{}", " ".repeat(content.len()), &line[content.len()..])
+        })
+        .collect()
+}
+
+fn toml_table_to_mapping(table: toml::Table) -> Mapping {
+    table
+        .into_iter()
+        .map(|(key, value)| (Value::from(key), toml_value_to_yaml(value)))
+        .collect()
+}
+
+fn toml_value_to_yaml(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(text) => Value::String(text),
+        toml::Value::Integer(number) => Value::Number(Number::from(number)),
+        toml::Value::Float(number) => Value::Number(Number::from(number)),
+        toml::Value::Boolean(flag) => Value::Bool(flag),
+        toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        toml::Value::Array(values) => {
+            Value::Sequence(values.into_iter().map(toml_value_to_yaml).collect())
+        }
+        toml::Value::Table(table) => Value::Mapping(toml_table_to_mapping(table)),
+    }
+}
+
+fn mapping_to_toml_table(fields: &Mapping) -> toml::Table {
+    fields
+        .iter()
+        .filter_map(|(key, value)| {
+            let key = match key {
+                Value::String(text) => text.clone(),
+                other => serde_yaml::to_string(other).ok()?.trim_end().to_string(),
+            };
+            Some((key, yaml_value_to_toml(value)?))
+        })
+        .collect()
+}
+
+fn yaml_value_to_toml(value: &Value) -> Option<toml::Value> {
+    match value {
+        Value::Null => None,
+        Value::Bool(flag) => Some(toml::Value::Boolean(*flag)),
+        Value::Number(number) => number
+            .as_i64()
+            .map(toml::Value::Integer)
+            .or_else(|| number.as_f64().map(toml::Value::Float)),
+        Value::String(text) => Some(toml::Value::String(text.clone())),
+        Value::Sequence(values) => Some(toml::Value::Array(
+            values.iter().filter_map(yaml_value_to_toml).collect(),
+        )),
+        Value::Mapping(mapping) => Some(toml::Value::Table(mapping_to_toml_table(mapping))),
+        Value::Tagged(tagged) => yaml_value_to_toml(&tagged.value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{doc_format_for_path, strip_doc_extension, BodySyntax, FrontmatterSyntax};
+    use serde_yaml::{Mapping, Value};
+    use std::path::Path;
+
+    #[test]
+    fn resolves_registered_formats_by_extension() {
+        assert_eq!(
+            doc_format_for_path(Path::new("guides/setup.mdx")).map(|format| format.body_syntax),
+            Some(BodySyntax::Mdx)
+        );
+        assert_eq!(
+            doc_format_for_path(Path::new("guides/setup.markdown"))
+                .map(|format| format.body_syntax),
+            Some(BodySyntax::Markdown)
+        );
+        assert_eq!(doc_format_for_path(Path::new("notes/todo.txt")), None);
+        assert_eq!(strip_doc_extension("setup.markdown"), "setup");
+        assert_eq!(strip_doc_extension("notes.txt"), "notes.txt");
+    }
+
+    #[test]
+    fn parses_and_serializes_toml_frontmatter() {
+        assert_eq!(
+            FrontmatterSyntax::detect("+++\ntitle = \"x\"\n+++\n"),
+            Some(FrontmatterSyntax::Toml)
+        );
+        assert_eq!(
+            FrontmatterSyntax::detect("---\ntitle: x\n---\n"),
+            Some(FrontmatterSyntax::Yaml)
+        );
+        assert_eq!(FrontmatterSyntax::detect("# No frontmatter\n"), None);
+
+        let fields = FrontmatterSyntax::Toml
+            .parse_mapping(
+                "title = \"Setup\"\ndate = 2026-02-19\ntags = [\"ops\", \"docs\"]",
+                Path::new("setup.md"),
+            )
+            .expect("toml frontmatter should parse");
+        assert_eq!(fields.get("title"), Some(&Value::from("Setup")));
+        assert_eq!(fields.get("date"), Some(&Value::from("2026-02-19")));
+        assert_eq!(
+            fields.get("tags"),
+            Some(&Value::Sequence(vec![
+                Value::from("ops"),
+                Value::from("docs")
+            ]))
+        );
+
+        let mut updated = Mapping::new();
+        updated.insert(Value::from("status"), Value::from("active"));
+        updated.insert(Value::from("milestone"), Value::Null);
+        assert_eq!(
+            FrontmatterSyntax::Toml
+                .serialize_mapping(&updated)
+                .expect("toml frontmatter should serialize"),
+            "status = \"active\""
+        );
+    }
+
+    #[test]
+    fn masks_mdx_esm_lines_without_shifting_offsets() {
+        let body = "import Chart from './chart'\n\n## Usage\n\n```js\nimport x from 'y'\n```\n";
+        let source = BodySyntax::Mdx.markdown_source(body);
+
+        assert_eq!(source.len(), body.len());
+        let esm_line = "import Chart from './chart'";
+        assert!(source.starts_with(&format!("{}\n\n## Usage", " ".repeat(esm_line.len()))));
+        assert!(source.contains("import x from 'y'"));
+        assert_eq!(BodySyntax::Markdown.markdown_source(body), body);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::doc_links::{build_doc_link_graph_for_documents, DocLink};
+use crate::plan_viewer::{load_doc_documents_from_root, DocDocument};
+use crate::project_registry::DocsRoot;
+use crate::project_runtime::ProjectRegistryState;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tauri::State;
+use time::{Date, Month, OffsetDateTime};
+
+const DEFAULT_STALE_PLAN_AGE_DAYS: u32 = 30;
+const PLANS_SECTION: &str = "plans";
+const ACTIVE_PLAN_STATUSES: [&str; 2] = ["active", "executing"];
+
+#[derive(Debug, Clone, Copy)]
+pub struct DocGardenerOptions {
+    pub stale_after_days: u32,
+    pub today: Date,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleActivePlan {
+    pub doc_id: String,
+    pub date: String,
+    pub age_days: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocGardenerReport {
+    pub checked_on: String,
+    pub stale_after_days: u32,
+    pub doc_count: usize,
+    pub broken_links: Vec<DocLink>,
+    pub missing_anchors: Vec<DocLink>,
+    pub orphan_doc_ids: Vec<String>,
+    pub stale_active_plans: Vec<StaleActivePlan>,
+}
+
+#[tauri::command]
+pub fn run_doc_gardener(
+    stale_after_days: Option<u32>,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<DocGardenerReport, String> {
+    let active_project = state.active_project_context()?;
+    run_doc_gardener_from_root(
+        &active_project.root_path,
+        &active_project.docs_roots,
+        &active_project.docs_exclude,
+        DocGardenerOptions {
+            stale_after_days: stale_after_days.unwrap_or(DEFAULT_STALE_PLAN_AGE_DAYS),
+            today: OffsetDateTime::now_utc().date(),
+        },
+    )
+}
+
+pub fn run_doc_gardener_from_root(
+    workspace_root: &Path,
+    docs_roots: &[DocsRoot],
+    docs_exclude: &[String],
+    options: DocGardenerOptions,
+) -> Result<DocGardenerReport, String> {
+    let mut documents = Vec::new();
+    let mut stale_active_plans = Vec::new();
+
+    for docs_root in docs_roots {
+        for document in load_doc_documents_from_root(workspace_root, &docs_root.path, docs_exclude)?
+        {
+            stale_active_plans.extend(stale_active_plan(&document, &options).map(|plan| {
+                StaleActivePlan {
+                    doc_id: docs_root.scoped_doc_id(&plan.doc_id),
+                    ..plan
+                }
+            }));
+            documents.push(document.scoped(docs_root));
+        }
+    }
+
+    let graph = build_doc_link_graph_for_documents(workspace_root, docs_roots, &documents)?;
+
+    let heading_slugs_by_doc_id = documents
+        .iter()
+        .map(|document| {
+            (
+                document.id.as_str(),
+                document
+                    .outline
+                    .iter()
+                    .map(|heading| heading.slug.as_str())
+                    .collect::<BTreeSet<&str>>(),
+            )
+        })
+        .collect::<BTreeMap<&str, BTreeSet<&str>>>();
+
+    let broken_links = graph
+        .links()
+        .iter()
+        .filter(|link| link.is_broken)
+        .cloned()
+        .collect::<Vec<DocLink>>();
+
+    let missing_anchors = graph
+        .links()
+        .iter()
+        .filter(|link| {
+            let (Some(target_doc_id), Some(anchor)) = (&link.target_doc_id, &link.anchor) else {
+                return false;
+            };
+
+            heading_slugs_by_doc_id
+                .get(target_doc_id.as_str())
+                .is_some_and(|slugs| !slugs.contains(anchor.to_lowercase().as_str()))
+        })
+        .cloned()
+        .collect::<Vec<DocLink>>();
+
+    let linked_doc_ids = graph
+        .links()
+        .iter()
+        .filter(|link| link.target_doc_id.as_deref() != Some(link.source_doc_id.as_str()))
+        .filter_map(|link| link.target_doc_id.clone())
+        .collect::<BTreeSet<String>>();
+
+    let orphan_doc_ids = documents
+        .iter()
+        .filter(|document| !document.is_hidden && !document.is_template)
+        .filter(|document| !linked_doc_ids.contains(&document.id))
+        .map(|document| document.id.clone())
+        .collect::<BTreeSet<String>>()
+        .into_iter()
+        .collect::<Vec<String>>();
+
+    stale_active_plans.sort_by(|left, right| {
+        right
+            .age_days
+            .cmp(&left.age_days)
+            .then_with(|| left.doc_id.cmp(&right.doc_id))
+    });
+
+    Ok(DocGardenerReport {
+        checked_on: format_date(options.today),
+        stale_after_days: options.stale_after_days,
+        doc_count: documents.len(),
+        broken_links,
+        missing_anchors,
+        orphan_doc_ids,
+        stale_active_plans,
+    })
+}
+
+fn stale_active_plan(
+    document: &DocDocument,
+    options: &DocGardenerOptions,
+) -> Option<StaleActivePlan> {
+    if document.section != PLANS_SECTION || document.is_template {
+        return None;
+    }
+
+    if !document
+        .status
+        .as_deref()
+        .is_some_and(|status| ACTIVE_PLAN_STATUSES.contains(&status))
+    {
+        return None;
+    }
+
+    let date_text = document.date.as_deref()?;
+    let plan_date = parse_iso_date(date_text)?;
+    let age_days = (options.today - plan_date).whole_days();
+
+    if age_days <= i64::from(options.stale_after_days) {
+        return None;
+    }
+
+    Some(StaleActivePlan {
+        doc_id: document.id.clone(),
+        date: date_text.to_string(),
+        age_days,
+    })
+}
+
+fn parse_iso_date(value: &str) -> Option<Date> {
+    let mut parts = value.trim().splitn(3, '-');
+    let year = parts.next()?.parse::<i32>().ok()?;
+    let month = parts.next()?.parse::<u8>().ok()?;
+    let day_text = parts.next()?;
+    let day_length = day_text
+        .find(|character: char| !character.is_ascii_digit())
+        .unwrap_or(day_text.len());
+    let day = day_text[..day_length].parse::<u8>().ok()?;
+
+    Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()
+}
+
+pub fn format_date(date: Date) -> String {
+    format!(
+        "{:04}-{:02}-{:02}",
+        date.year(),
+        u8::from(date.month()),
+        date.day()
+    )
+}
+
+#[cfg(test)]
+#[path = "doc_gardener_tests.rs"]
+mod tests;
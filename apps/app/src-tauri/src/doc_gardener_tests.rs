@@ -0,0 +1,184 @@
+use super::{run_doc_gardener_from_root, DocGardenerOptions};
+use crate::project_registry::DocsRoot;
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
+use std::path::Path;
+use time::{Date, Month};
+
+fn options(stale_after_days: u32) -> DocGardenerOptions {
+    DocGardenerOptions {
+        stale_after_days,
+        today: Date::from_calendar_date(2026, Month::March, 31).expect("valid date"),
+    }
+}
+
+fn primary_docs_root(workspace: &Path) -> Vec<DocsRoot> {
+    vec![DocsRoot {
+        namespace: None,
+        path: workspace.join("docs"),
+    }]
+}
+
+fn seed_knowledge_base(workspace: &Path) {
+    let docs_root = workspace.join("docs");
+
+    write_doc_file(
+        &docs_root.join("design-docs/index.md"),
+        "---\ntitle: Index\ndate: 2026-02-14\n---\n\n- [Beliefs](./core-beliefs.md#principles)\n- [Workflows](./agent-workflows.md#missing-heading)\n- [Old plan](../plans/active/2026-02-01-feat-old-plan.md)\n- [Gone](./removed.md)\n",
+    );
+    write_doc_file(
+        &docs_root.join("design-docs/core-beliefs.md"),
+        "---\ntitle: Core Beliefs\ndate: 2026-02-14\n---\n\n## Principles\n\nBody\n",
+    );
+    write_doc_file(
+        &docs_root.join("design-docs/agent-workflows.md"),
+        "---\ntitle: Agent Workflows\ndate: 2026-02-14\n---\n\n## Overview\n\nBack to [index](./index.md).\n",
+    );
+    write_doc_file(
+        &docs_root.join("plans/active/2026-02-01-feat-old-plan.md"),
+        "---\ntitle: Old Plan\ndate: 2026-02-01\nstatus: active\n---\n\n## Goal\n",
+    );
+    write_doc_file(
+        &docs_root.join("plans/active/2026-03-20-feat-new-plan.md"),
+        "---\ntitle: New Plan\ndate: 2026-03-20\nstatus: active\n---\n\n## Goal\n",
+    );
+    write_doc_file(
+        &docs_root.join("plans/.template.md"),
+        "---\ntitle:\ndate:\nstatus: draft\n---\n\n## Goal\n",
+    );
+}
+
+#[test]
+fn reports_broken_links_and_missing_anchors() {
+    let workspace = create_temp_workspace("doc-gardener");
+    seed_knowledge_base(&workspace);
+
+    let report =
+        run_doc_gardener_from_root(&workspace, &primary_docs_root(&workspace), &[], options(30))
+            .expect("gardener should run");
+
+    assert_eq!(report.doc_count, 6);
+    assert_eq!(report.broken_links.len(), 1);
+    assert_eq!(report.broken_links[0].raw_target, "./removed.md");
+    assert_eq!(report.missing_anchors.len(), 1);
+    assert_eq!(
+        report.missing_anchors[0].target_doc_id.as_deref(),
+        Some("design-docs/agent-workflows.md")
+    );
+    assert_eq!(
+        report.missing_anchors[0].anchor.as_deref(),
+        Some("missing-heading")
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn reports_orphans_and_stale_active_plans() {
+    let workspace = create_temp_workspace("doc-gardener");
+    seed_knowledge_base(&workspace);
+
+    let report =
+        run_doc_gardener_from_root(&workspace, &primary_docs_root(&workspace), &[], options(30))
+            .expect("gardener should run");
+
+    assert_eq!(
+        report.orphan_doc_ids,
+        vec!["plans/active/2026-03-20-feat-new-plan.md".to_string()]
+    );
+    assert_eq!(report.stale_active_plans.len(), 1);
+    assert_eq!(
+        report.stale_active_plans[0].doc_id,
+        "plans/active/2026-02-01-feat-old-plan.md"
+    );
+    assert_eq!(report.stale_active_plans[0].age_days, 58);
+
+    let relaxed_report =
+        run_doc_gardener_from_root(&workspace, &primary_docs_root(&workspace), &[], options(60))
+            .expect("gardener should run");
+    assert!(relaxed_report.stale_active_plans.is_empty());
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn reports_stale_active_plans_with_unpadded_dates() {
+    let workspace = create_temp_workspace("doc-gardener-unpadded-date");
+    write_doc_file(
+        &workspace.join("docs/plans/active/2026-02-01-feat-unpadded-plan.md"),
+        "---\ntitle: Unpadded Plan\ndate: 2026-2-1\nstatus: active\n---\n\n## Goal\n",
+    );
+
+    let report =
+        run_doc_gardener_from_root(&workspace, &primary_docs_root(&workspace), &[], options(30))
+            .expect("gardener should run");
+
+    assert_eq!(report.stale_active_plans.len(), 1);
+    assert_eq!(
+        report.stale_active_plans[0].doc_id,
+        "plans/active/2026-02-01-feat-unpadded-plan.md"
+    );
+    assert_eq!(report.stale_active_plans[0].date, "2026-2-1");
+    assert_eq!(report.stale_active_plans[0].age_days, 58);
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn reports_across_all_docs_roots_with_scoped_ids() {
+    let workspace = create_temp_workspace("doc-gardener");
+    seed_knowledge_base(&workspace);
+    write_doc_file(
+        &workspace.join("adr/0001-storage.md"),
+        "---\ntitle: Storage\ndate: 2026-02-14\n---\n\n## Decision\n\nSee [plan](../docs/plans/active/2026-03-20-feat-new-plan.md) and [gone](./0009-gone.md).\n",
+    );
+    write_doc_file(
+        &workspace.join("adr/plans/active/2026-01-01-feat-adr-plan.md"),
+        "---\ntitle: ADR Plan\ndate: 2026-01-01\nstatus: active\n---\n\n## Goal\n",
+    );
+    let mut docs_roots = primary_docs_root(&workspace);
+    docs_roots.push(DocsRoot {
+        namespace: Some("adr".to_string()),
+        path: workspace.join("adr"),
+    });
+
+    let report = run_doc_gardener_from_root(&workspace, &docs_roots, &[], options(30))
+        .expect("gardener should run");
+
+    assert_eq!(report.doc_count, 8);
+    assert_eq!(report.broken_links.len(), 2);
+    assert!(report
+        .broken_links
+        .iter()
+        .any(|link| link.source_doc_id == "adr:0001-storage.md"));
+    assert_eq!(
+        report.orphan_doc_ids,
+        vec![
+            "adr:0001-storage.md".to_string(),
+            "adr:plans/active/2026-01-01-feat-adr-plan.md".to_string(),
+        ]
+    );
+    assert_eq!(
+        report.stale_active_plans[0].doc_id,
+        "adr:plans/active/2026-01-01-feat-adr-plan.md"
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn serializes_report_for_headless_consumers() {
+    let workspace = create_temp_workspace("doc-gardener");
+    seed_knowledge_base(&workspace);
+
+    let report =
+        run_doc_gardener_from_root(&workspace, &primary_docs_root(&workspace), &[], options(30))
+            .expect("gardener should run");
+    let json = serde_json::to_value(&report).expect("report should serialize");
+
+    assert_eq!(json["checkedOn"], "2026-03-31");
+    assert_eq!(json["staleAfterDays"], 30);
+    assert!(json["brokenLinks"].is_array());
+    assert!(json["orphanDocIds"].is_array());
+
+    teardown_workspace(&workspace);
+}
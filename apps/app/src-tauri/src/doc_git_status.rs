@@ -0,0 +1,235 @@
+use crate::doc_format::has_doc_extension;
+use crate::doc_history::run_git;
+use crate::project_registry::DocsRoot;
+use crate::project_runtime::ProjectRegistryState;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use tauri::State;
+
+const GIT_INDEX_FILE_NAME: &str = "index";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocGitStatus {
+    Untracked,
+    Modified,
+    Staged,
+    Clean,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DirtyDoc {
+    doc_id: String,
+    status: DocGitStatus,
+}
+
+#[derive(Debug)]
+pub struct DocGitStatusSnapshot {
+    index_path: PathBuf,
+    index_modified_at: Option<SystemTime>,
+    statuses: BTreeMap<String, DocGitStatus>,
+}
+
+#[derive(Clone, Default)]
+pub struct DocGitStatusState {
+    inner: Arc<Mutex<HashMap<PathBuf, Arc<DocGitStatusSnapshot>>>>,
+}
+
+impl DocGitStatusSnapshot {
+    pub fn status_for(&self, doc_id: &str) -> DocGitStatus {
+        self.statuses
+            .get(doc_id)
+            .copied()
+            .unwrap_or(DocGitStatus::Clean)
+    }
+
+    pub fn dirty_docs(&self, docs_root: &DocsRoot) -> Vec<DirtyDoc> {
+        self.statuses
+            .iter()
+            .map(|(doc_id, status)| DirtyDoc {
+                doc_id: docs_root.scoped_doc_id(doc_id),
+                status: *status,
+            })
+            .collect()
+    }
+
+    fn is_current(&self) -> bool {
+        file_modified_at(&self.index_path) == self.index_modified_at
+    }
+}
+
+impl DocGitStatusState {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn snapshot(
+        &self,
+        workspace_root: &Path,
+        docs_root: &Path,
+    ) -> Result<Arc<DocGitStatusSnapshot>, String> {
+        let docs_root_canonical = docs_root.canonicalize().map_err(|error| {
+            format!(
+                "git status failed: cannot resolve docs directory {}: {error}",
+                docs_root.display()
+            )
+        })?;
+
+        let cached_snapshot = self
+            .inner
+            .lock()
+            .map_err(|_| "doc git status state lock poisoned".to_string())?
+            .get(&docs_root_canonical)
+            .cloned();
+        if let Some(snapshot) = cached_snapshot.filter(|snapshot| snapshot.is_current()) {
+            return Ok(snapshot);
+        }
+
+        let snapshot = Arc::new(load_doc_git_status_snapshot(
+            workspace_root,
+            &docs_root_canonical,
+        )?);
+        self.inner
+            .lock()
+            .map_err(|_| "doc git status state lock poisoned".to_string())?
+            .insert(docs_root_canonical, snapshot.clone());
+
+        Ok(snapshot)
+    }
+
+    pub fn invalidate(&self, docs_root: &Path) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.remove(docs_root);
+        }
+    }
+}
+
+#[tauri::command]
+pub fn list_dirty_docs(
+    state: State<'_, ProjectRegistryState>,
+    git_status_state: State<'_, DocGitStatusState>,
+) -> Result<Vec<DirtyDoc>, String> {
+    let active_project = state.active_project_context()?;
+    let mut dirty_docs = Vec::new();
+
+    for docs_root in &active_project.docs_roots {
+        let snapshot = git_status_state.snapshot(&active_project.root_path, &docs_root.path)?;
+        dirty_docs.extend(snapshot.dirty_docs(docs_root));
+    }
+
+    Ok(dirty_docs)
+}
+
+pub fn load_doc_git_status_snapshot(
+    workspace_root: &Path,
+    docs_root: &Path,
+) -> Result<DocGitStatusSnapshot, String> {
+    let docs_root_canonical = docs_root.canonicalize().map_err(|error| {
+        format!(
+            "git status failed: cannot resolve docs directory {}: {error}",
+            docs_root.display()
+        )
+    })?;
+
+    let repository_paths = run_git(
+        workspace_root,
+        &["rev-parse", "--show-toplevel", "--absolute-git-dir"],
+    )?;
+    let mut repository_lines = repository_paths.lines();
+    let (Some(repository_root), Some(git_dir)) = (repository_lines.next(), repository_lines.next())
+    else {
+        return Err("git status failed: cannot resolve repository paths".to_string());
+    };
+    let repository_root = PathBuf::from(repository_root)
+        .canonicalize()
+        .map_err(|error| format!("git status failed: cannot resolve repository root: {error}"))?;
+    let index_path = PathBuf::from(git_dir).join(GIT_INDEX_FILE_NAME);
+    let index_modified_at = file_modified_at(&index_path);
+
+    let porcelain = run_git(
+        workspace_root,
+        &[
+            "status",
+            "--porcelain=v1",
+            "-z",
+            "--untracked-files=all",
+            "--",
+            &docs_root_canonical.to_string_lossy(),
+        ],
+    )?;
+
+    let statuses = parse_porcelain_statuses(&porcelain)
+        .into_iter()
+        .filter_map(|(path, status)| {
+            let doc_id = doc_id_for_repository_path(&repository_root, &docs_root_canonical, path)?;
+            Some((doc_id, status))
+        })
+        .collect();
+
+    Ok(DocGitStatusSnapshot {
+        index_path,
+        index_modified_at,
+        statuses,
+    })
+}
+
+fn parse_porcelain_statuses(porcelain: &str) -> Vec<(&str, DocGitStatus)> {
+    let mut statuses = Vec::new();
+    let mut entries = porcelain.split('\0').filter(|entry| !entry.is_empty());
+
+    while let Some(entry) = entries.next() {
+        let (Some(code), Some(path)) = (entry.get(..2), entry.get(2..)) else {
+            continue;
+        };
+        let path = path.trim_start_matches(' ');
+        let mut code_chars = code.chars();
+        let index_code = code_chars.next().unwrap_or(' ');
+        let worktree_code = code_chars.next().unwrap_or(' ');
+
+        if matches!(index_code, 'R' | 'C') {
+            entries.next();
+        }
+
+        let status = match (index_code, worktree_code) {
+            ('?', '?') => DocGitStatus::Untracked,
+            ('!', '!') => continue,
+            (_, worktree) if worktree != ' ' => DocGitStatus::Modified,
+            _ => DocGitStatus::Staged,
+        };
+        statuses.push((path, status));
+    }
+
+    statuses
+}
+
+fn doc_id_for_repository_path(
+    repository_root: &Path,
+    docs_root: &Path,
+    repository_path: &str,
+) -> Option<String> {
+    let relative_path = repository_root
+        .join(repository_path)
+        .strip_prefix(docs_root)
+        .ok()?
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    has_doc_extension(&relative_path).then_some(relative_path)
+}
+
+fn file_modified_at(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+#[path = "doc_git_status_tests.rs"]
+mod tests;
@@ -0,0 +1,157 @@
+use super::{
+    load_doc_git_status_snapshot, parse_porcelain_statuses, DocGitStatus, DocGitStatusState,
+};
+use crate::project_registry::DocsRoot;
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn git(workspace: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(workspace)
+        .args([
+            "-c",
+            "user.name=Doc Author",
+            "-c",
+            "user.email=author@example.com",
+        ])
+        .args(args)
+        .status()
+        .expect("git should run");
+    assert!(status.success(), "git {args:?} should succeed");
+}
+
+fn committed_workspace() -> PathBuf {
+    let workspace = create_temp_workspace("doc-git-status");
+    let docs_root = workspace.join("docs");
+    git(&workspace, &["init", "--quiet"]);
+    write_doc_file(
+        &docs_root.join("plans/clean.md"),
+        "---\ntitle: Clean\n---\n",
+    );
+    write_doc_file(
+        &docs_root.join("plans/edited.md"),
+        "---\ntitle: Edited\n---\n",
+    );
+    write_doc_file(
+        &docs_root.join("plans/staged.md"),
+        "---\ntitle: Staged\n---\n",
+    );
+    write_doc_file(&workspace.join("README.md"), "outside docs\n");
+    git(&workspace, &["add", "-A"]);
+    git(&workspace, &["commit", "--quiet", "-m", "Add docs"]);
+    workspace
+}
+
+#[test]
+fn classifies_working_tree_changes_by_doc_id() {
+    let workspace = committed_workspace();
+    let docs_root = workspace.join("docs");
+    write_doc_file(
+        &docs_root.join("plans/edited.md"),
+        "---\ntitle: Edited\n---\n\nChanged.\n",
+    );
+    write_doc_file(
+        &docs_root.join("plans/staged.md"),
+        "---\ntitle: Staged\n---\n\nStaged.\n",
+    );
+    git(&workspace, &["add", "docs/plans/staged.md"]);
+    write_doc_file(
+        &docs_root.join("solutions/new.md"),
+        "---\ntitle: New\n---\n",
+    );
+    write_doc_file(&workspace.join("README.md"), "outside docs changed\n");
+
+    let snapshot =
+        load_doc_git_status_snapshot(&workspace, &docs_root).expect("git status should load");
+
+    assert_eq!(snapshot.status_for("plans/clean.md"), DocGitStatus::Clean);
+    assert_eq!(
+        snapshot.status_for("plans/edited.md"),
+        DocGitStatus::Modified
+    );
+    assert_eq!(snapshot.status_for("plans/staged.md"), DocGitStatus::Staged);
+    assert_eq!(
+        snapshot.status_for("solutions/new.md"),
+        DocGitStatus::Untracked
+    );
+
+    let docs_root = DocsRoot {
+        namespace: Some("notes".to_string()),
+        path: docs_root,
+    };
+    let dirty =
+        serde_json::to_value(snapshot.dirty_docs(&docs_root)).expect("dirty docs should serialize");
+    assert_eq!(
+        dirty,
+        serde_json::json!([
+            { "docId": "notes:plans/edited.md", "status": "modified" },
+            { "docId": "notes:plans/staged.md", "status": "staged" },
+            { "docId": "notes:solutions/new.md", "status": "untracked" }
+        ])
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn cached_snapshot_refreshes_on_invalidation_and_index_changes() {
+    let workspace = committed_workspace();
+    let docs_root = workspace.join("docs");
+    let state = DocGitStatusState::new();
+
+    let initial = state
+        .snapshot(&workspace, &docs_root)
+        .expect("git status should load");
+    assert_eq!(initial.status_for("plans/edited.md"), DocGitStatus::Clean);
+
+    write_doc_file(
+        &docs_root.join("plans/edited.md"),
+        "---\ntitle: Edited\n---\n\nChanged.\n",
+    );
+    let cached = state
+        .snapshot(&workspace, &docs_root)
+        .expect("git status should load");
+    assert_eq!(cached.status_for("plans/edited.md"), DocGitStatus::Clean);
+
+    state.invalidate(&docs_root);
+    let refreshed = state
+        .snapshot(&workspace, &docs_root)
+        .expect("git status should load");
+    assert_eq!(
+        refreshed.status_for("plans/edited.md"),
+        DocGitStatus::Modified
+    );
+
+    git(&workspace, &["add", "docs/plans/edited.md"]);
+    let restaged = state
+        .snapshot(&workspace, &docs_root)
+        .expect("git status should load");
+    assert_eq!(restaged.status_for("plans/edited.md"), DocGitStatus::Staged);
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn reports_error_outside_git_repository() {
+    let workspace = create_temp_workspace("doc-git-status");
+    let docs_root = workspace.join("docs");
+    write_doc_file(
+        &docs_root.join("plans/loose.md"),
+        "---\ntitle: Loose\n---\n",
+    );
+
+    let error = load_doc_git_status_snapshot(&workspace, &docs_root)
+        .expect_err("non-repository should not report status");
+    assert!(error.contains("git rev-parse failed"), "{error}");
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn skips_porcelain_entries_without_a_status_code() {
+    let statuses = parse_porcelain_statuses("M\0\u{20ac}x\0?? docs/new.md\0");
+
+    assert_eq!(statuses, vec![("docs/new.md", DocGitStatus::Untracked)]);
+}
@@ -0,0 +1,220 @@
+use crate::plan_viewer::get_doc_document_from_root;
+use crate::project_runtime::ProjectRegistryState;
+use serde::Serialize;
+use std::path::Path;
+use std::process::Command;
+use tauri::State;
+
+const DEFAULT_HISTORY_LIMIT: usize = 50;
+const GIT_FIELD_SEPARATOR: char = '\u{1f}';
+const GIT_RECORD_SEPARATOR: char = '\u{1e}';
+const GIT_LOG_FORMAT: &str = "--format=%H%x1f%h%x1f%an%x1f%ae%x1f%aI%x1f%s%x1e";
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DocCommit {
+    pub hash: String,
+    pub short_hash: String,
+    pub author_name: String,
+    pub author_email: String,
+    pub authored_at_iso: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocRevisionContent {
+    pub doc_id: String,
+    pub revision: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocRevisionDiff {
+    pub doc_id: String,
+    pub from_revision: String,
+    pub to_revision: Option<String>,
+    pub diff: String,
+}
+
+#[tauri::command]
+pub fn list_doc_history(
+    doc_id: String,
+    limit: Option<usize>,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<Vec<DocCommit>, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&doc_id)?;
+    list_doc_history_from_root(
+        &active_project.root_path,
+        &docs_root.path,
+        &doc_id,
+        limit.unwrap_or(DEFAULT_HISTORY_LIMIT),
+    )
+}
+
+#[tauri::command]
+pub fn get_doc_at_revision(
+    doc_id: String,
+    revision: String,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<DocRevisionContent, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&doc_id)?;
+    get_doc_at_revision_from_root(
+        &active_project.root_path,
+        &docs_root.path,
+        &doc_id,
+        &revision,
+    )
+    .map(|content| DocRevisionContent {
+        doc_id: docs_root.scoped_doc_id(&content.doc_id),
+        ..content
+    })
+}
+
+#[tauri::command]
+pub fn diff_doc_revisions(
+    doc_id: String,
+    from_revision: String,
+    to_revision: Option<String>,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<DocRevisionDiff, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&doc_id)?;
+    diff_doc_revisions_from_root(
+        &active_project.root_path,
+        &docs_root.path,
+        &doc_id,
+        &from_revision,
+        to_revision.as_deref(),
+    )
+    .map(|diff| DocRevisionDiff {
+        doc_id: docs_root.scoped_doc_id(&diff.doc_id),
+        ..diff
+    })
+}
+
+pub fn list_doc_history_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    limit: usize,
+) -> Result<Vec<DocCommit>, String> {
+    let document = get_doc_document_from_root(workspace_root, docs_root, doc_id)?;
+    let output = run_git(
+        workspace_root,
+        &[
+            "log",
+            "--follow",
+            &format!("--max-count={}", limit.max(1)),
+            GIT_LOG_FORMAT,
+            "--",
+            &document.relative_path,
+        ],
+    )?;
+
+    Ok(output
+        .split(GIT_RECORD_SEPARATOR)
+        .filter_map(parse_commit_record)
+        .collect())
+}
+
+pub fn get_doc_at_revision_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    revision: &str,
+) -> Result<DocRevisionContent, String> {
+    let document = get_doc_document_from_root(workspace_root, docs_root, doc_id)?;
+    let revision = validate_revision(revision)?;
+    let content = run_git(
+        workspace_root,
+        &["show", &format!("{revision}:./{}", document.relative_path)],
+    )?;
+
+    Ok(DocRevisionContent {
+        doc_id: document.id,
+        revision: revision.to_string(),
+        content,
+    })
+}
+
+pub fn diff_doc_revisions_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    from_revision: &str,
+    to_revision: Option<&str>,
+) -> Result<DocRevisionDiff, String> {
+    let document = get_doc_document_from_root(workspace_root, docs_root, doc_id)?;
+    let from_revision = validate_revision(from_revision)?;
+    let to_revision = to_revision.map(validate_revision).transpose()?;
+
+    let mut args = vec!["diff", "--no-color", "--no-ext-diff", from_revision];
+    args.extend(to_revision);
+    args.extend(["--", document.relative_path.as_str()]);
+    let diff = run_git(workspace_root, &args)?;
+
+    Ok(DocRevisionDiff {
+        doc_id: document.id,
+        from_revision: from_revision.to_string(),
+        to_revision: to_revision.map(str::to_string),
+        diff,
+    })
+}
+
+fn validate_revision(revision: &str) -> Result<&str, String> {
+    let trimmed = revision.trim();
+    let is_valid = !trimmed.is_empty()
+        && !trimmed.starts_with('-')
+        && !trimmed.contains("..")
+        && trimmed.chars().all(|character| {
+            character.is_ascii_alphanumeric()
+                || matches!(character, '.' | '_' | '/' | '~' | '^' | '-')
+        });
+
+    if !is_valid {
+        return Err(format!("invalid git revision: {revision}"));
+    }
+
+    Ok(trimmed)
+}
+
+fn parse_commit_record(record: &str) -> Option<DocCommit> {
+    let mut fields = record.trim_matches('\n').split(GIT_FIELD_SEPARATOR);
+
+    Some(DocCommit {
+        hash: fields.next().filter(|hash| !hash.is_empty())?.to_string(),
+        short_hash: fields.next()?.to_string(),
+        author_name: fields.next()?.to_string(),
+        author_email: fields.next()?.to_string(),
+        authored_at_iso: fields.next()?.to_string(),
+        message: fields.next()?.to_string(),
+    })
+}
+
+pub fn run_git(workspace_root: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(workspace_root)
+        .args(args)
+        .env("GIT_OPTIONAL_LOCKS", "0")
+        .output()
+        .map_err(|error| format!("failed to run git: {error}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    String::from_utf8(output.stdout).map_err(|error| format!("git output is not utf-8: {error}"))
+}
+
+#[cfg(test)]
+#[path = "doc_history_tests.rs"]
+mod tests;
@@ -0,0 +1,137 @@
+use super::{
+    diff_doc_revisions_from_root, get_doc_at_revision_from_root, list_doc_history_from_root,
+};
+use crate::test_support::{self, teardown_workspace};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const DOC_ID: &str = "solutions/history.md";
+
+fn create_temp_workspace() -> PathBuf {
+    let workspace_path = test_support::create_temp_workspace("doc-history");
+    git(&workspace_path, &["init", "--quiet"]);
+    workspace_path
+}
+
+fn git(workspace: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(workspace)
+        .args([
+            "-c",
+            "user.name=Doc Author",
+            "-c",
+            "user.email=author@example.com",
+        ])
+        .args(args)
+        .env("GIT_AUTHOR_DATE", "2026-02-19T09:30:00+00:00")
+        .env("GIT_COMMITTER_DATE", "2026-02-19T09:30:00+00:00")
+        .status()
+        .expect("git should run");
+    assert!(status.success(), "git {args:?} should succeed");
+}
+
+fn commit_doc(workspace: &Path, contents: &str, message: &str) {
+    let path = workspace.join("docs").join(DOC_ID);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("parent directory should be created");
+    }
+    fs::write(&path, contents).expect("document file should be written");
+    git(workspace, &["add", "-A"]);
+    git(workspace, &["commit", "--quiet", "-m", message]);
+}
+
+#[test]
+fn lists_commits_and_reads_content_at_revision() {
+    let workspace = create_temp_workspace();
+    let docs_root = workspace.join("docs");
+    commit_doc(
+        &workspace,
+        "---\ntitle: History\n---\n\nFirst.\n",
+        "Add history doc",
+    );
+    commit_doc(
+        &workspace,
+        "---\ntitle: History\n---\n\nSecond.\n",
+        "Revise history doc",
+    );
+    fs::write(docs_root.join("other.md"), "---\ntitle: Other\n---\n").expect("write other");
+    git(&workspace, &["add", "-A"]);
+    git(&workspace, &["commit", "--quiet", "-m", "Unrelated doc"]);
+
+    let history = list_doc_history_from_root(&workspace, &docs_root, DOC_ID, 10)
+        .expect("history should load");
+
+    assert_eq!(
+        history
+            .iter()
+            .map(|commit| commit.message.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["Revise history doc", "Add history doc"]
+    );
+    assert_eq!(history[0].author_name, "Doc Author");
+    assert_eq!(history[0].author_email, "author@example.com");
+    assert_eq!(history[0].authored_at_iso, "2026-02-19T09:30:00+00:00");
+
+    let first = get_doc_at_revision_from_root(&workspace, &docs_root, DOC_ID, &history[1].hash)
+        .expect("first revision should load");
+    assert_eq!(first.content, "---\ntitle: History\n---\n\nFirst.\n");
+
+    let limited =
+        list_doc_history_from_root(&workspace, &docs_root, DOC_ID, 1).expect("history should load");
+    assert_eq!(limited.len(), 1);
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn diffs_revisions_and_working_tree() {
+    let workspace = create_temp_workspace();
+    let docs_root = workspace.join("docs");
+    commit_doc(
+        &workspace,
+        "---\ntitle: History\n---\n\nFirst.\n",
+        "Add history doc",
+    );
+    commit_doc(
+        &workspace,
+        "---\ntitle: History\n---\n\nSecond.\n",
+        "Revise history doc",
+    );
+
+    let diff = diff_doc_revisions_from_root(&workspace, &docs_root, DOC_ID, "HEAD~1", Some("HEAD"))
+        .expect("diff should load");
+    assert!(diff.diff.contains("--- a/docs/solutions/history.md"));
+    assert!(diff.diff.contains("-First.\n+Second.\n"));
+
+    fs::write(
+        docs_root.join(DOC_ID),
+        "---\ntitle: History\n---\n\nThird.\n",
+    )
+    .expect("working tree edit should be written");
+    let working_tree_diff =
+        diff_doc_revisions_from_root(&workspace, &docs_root, DOC_ID, "HEAD", None)
+            .expect("working tree diff should load");
+    assert!(working_tree_diff.diff.contains("-Second.\n+Third.\n"));
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn rejects_option_like_revisions() {
+    let workspace = create_temp_workspace();
+    let docs_root = workspace.join("docs");
+    commit_doc(&workspace, "---\ntitle: History\n---\n", "Add history doc");
+
+    for revision in ["--output=/tmp/x", "HEAD..main", "", "HEAD:secret"] {
+        let error = get_doc_at_revision_from_root(&workspace, &docs_root, DOC_ID, revision)
+            .expect_err("unsafe revision should be rejected");
+        assert!(
+            error.contains("invalid git revision"),
+            "{revision}: {error}"
+        );
+    }
+
+    teardown_workspace(&workspace);
+}
@@ -0,0 +1,364 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+const IGNORE_FILE_NAMES: [&str; 2] = [".gitignore", ".codaignore"];
+const GIT_DIR_NAME: &str = ".git";
+const GIT_INFO_EXCLUDE_PATH: &str = ".git/info/exclude";
+const ANY_DEPTH_SEGMENT: [char; 2] = ['*', '*'];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IgnoreRule {
+    base: PathBuf,
+    segments: Vec<Vec<char>>,
+    anchored: bool,
+    negated: bool,
+    directory_only: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocIgnoreRules {
+    workspace_root: PathBuf,
+    loaded_dirs: BTreeSet<PathBuf>,
+    rules: Vec<IgnoreRule>,
+    exclude_rules: Vec<IgnoreRule>,
+}
+
+pub fn is_ignore_file(path: &Path) -> bool {
+    path.ends_with(GIT_INFO_EXCLUDE_PATH)
+        || path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .is_some_and(|file_name| IGNORE_FILE_NAMES.contains(&file_name))
+}
+
+impl DocIgnoreRules {
+    pub fn load(workspace_root: &Path, docs_roots: &[PathBuf], exclude_globs: &[String]) -> Self {
+        let repository_root = find_repository_root(workspace_root);
+        let mut rules = repository_root
+            .as_deref()
+            .map(read_git_info_exclude)
+            .unwrap_or_default();
+        let mut loaded_dirs = BTreeSet::new();
+        let ignore_files_root = repository_root.as_deref().unwrap_or(workspace_root);
+
+        for docs_root in docs_roots {
+            for dir in ignore_file_dirs(ignore_files_root, docs_root) {
+                if loaded_dirs.insert(dir.clone()) {
+                    rules.extend(read_ignore_files(&dir));
+                }
+            }
+        }
+
+        Self {
+            workspace_root: workspace_root.to_path_buf(),
+            loaded_dirs,
+            rules,
+            exclude_rules: exclude_globs
+                .iter()
+                .filter_map(|pattern| parse_ignore_rule(workspace_root, pattern))
+                .collect(),
+        }
+    }
+
+    pub fn with_dir_ignore_files(&self, dir: &Path) -> Option<Self> {
+        if self.loaded_dirs.contains(dir) {
+            return None;
+        }
+
+        let dir_rules = read_ignore_files(dir);
+        if dir_rules.is_empty() {
+            return None;
+        }
+
+        let mut rules = self.clone();
+        rules.loaded_dirs.insert(dir.to_path_buf());
+        rules.rules.extend(dir_rules);
+        Some(rules)
+    }
+
+    pub fn matches(&self, path: &Path, is_dir: bool) -> bool {
+        let mut ignored = false;
+
+        for rule in self.rules.iter().chain(&self.exclude_rules) {
+            if rule.directory_only && !is_dir {
+                continue;
+            }
+
+            if rule.matches(path) {
+                ignored = !rule.negated;
+            }
+        }
+
+        ignored
+    }
+
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        let mut ancestors = path
+            .ancestors()
+            .skip(1)
+            .take_while(|ancestor| ancestor.starts_with(&self.workspace_root))
+            .collect::<Vec<&Path>>();
+        ancestors.reverse();
+
+        let mut rules = Cow::Borrowed(self);
+        for ancestor in ancestors {
+            if ancestor != self.workspace_root && rules.matches(ancestor, true) {
+                return true;
+            }
+
+            if let Some(dir_rules) = rules.with_dir_ignore_files(ancestor) {
+                rules = Cow::Owned(dir_rules);
+            }
+        }
+
+        rules.matches(path, is_dir)
+    }
+}
+
+impl IgnoreRule {
+    fn matches(&self, path: &Path) -> bool {
+        let Ok(relative_path) = path.strip_prefix(&self.base) else {
+            return false;
+        };
+        let components = relative_path
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().chars().collect())
+            .collect::<Vec<Vec<char>>>();
+
+        if components.is_empty() {
+            return false;
+        }
+
+        if self.anchored {
+            return segments_match(&self.segments, &components);
+        }
+
+        components
+            .last()
+            .is_some_and(|name| wildcard_matches(&self.segments[0], name))
+    }
+}
+
+fn find_repository_root(workspace_root: &Path) -> Option<PathBuf> {
+    workspace_root
+        .ancestors()
+        .find(|dir| dir.join(GIT_DIR_NAME).exists())
+        .map(Path::to_path_buf)
+}
+
+fn read_git_info_exclude(repository_root: &Path) -> Vec<IgnoreRule> {
+    if !repository_root.join(GIT_DIR_NAME).is_dir() {
+        return Vec::new();
+    }
+
+    read_ignore_file(
+        repository_root,
+        &repository_root.join(GIT_INFO_EXCLUDE_PATH),
+    )
+}
+
+fn ignore_file_dirs(ignore_files_root: &Path, docs_root: &Path) -> Vec<PathBuf> {
+    let Ok(relative_docs_root) = docs_root.strip_prefix(ignore_files_root) else {
+        return vec![docs_root.to_path_buf()];
+    };
+
+    let mut dir = ignore_files_root.to_path_buf();
+    let mut dirs = vec![dir.clone()];
+    for component in relative_docs_root.components() {
+        dir.push(component);
+        dirs.push(dir.clone());
+    }
+
+    dirs
+}
+
+fn read_ignore_files(dir: &Path) -> Vec<IgnoreRule> {
+    IGNORE_FILE_NAMES
+        .iter()
+        .flat_map(|file_name| read_ignore_file(dir, &dir.join(file_name)))
+        .collect()
+}
+
+fn read_ignore_file(base: &Path, path: &Path) -> Vec<IgnoreRule> {
+    match fs::read_to_string(path) {
+        Ok(contents) => contents
+            .lines()
+            .filter_map(|line| parse_ignore_rule(base, line))
+            .collect(),
+        Err(error) if error.kind() == ErrorKind::NotFound => Vec::new(),
+        Err(error) => {
+            log::warn!("failed to read ignore file {}: {error}", path.display());
+            Vec::new()
+        }
+    }
+}
+
+fn parse_ignore_rule(base: &Path, line: &str) -> Option<IgnoreRule> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let (negated, pattern) = match line.strip_prefix('!') {
+        Some(pattern) => (true, pattern),
+        None => (false, line.strip_prefix('\\').unwrap_or(line)),
+    };
+    let (directory_only, pattern) = match pattern.strip_suffix('/') {
+        Some(pattern) => (true, pattern),
+        None => (false, pattern),
+    };
+    let anchored = pattern.contains('/');
+    let segments = pattern
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.chars().collect())
+        .collect::<Vec<Vec<char>>>();
+
+    if segments.is_empty() {
+        return None;
+    }
+
+    Some(IgnoreRule {
+        base: base.to_path_buf(),
+        segments,
+        anchored,
+        negated,
+        directory_only,
+    })
+}
+
+fn segments_match(segments: &[Vec<char>], components: &[Vec<char>]) -> bool {
+    match segments.split_first() {
+        None => components.is_empty(),
+        Some((segment, remaining_segments)) if segment[..] == ANY_DEPTH_SEGMENT => (0..=components
+            .len())
+            .any(|skipped| segments_match(remaining_segments, &components[skipped..])),
+        Some((segment, remaining_segments)) => {
+            components
+                .split_first()
+                .is_some_and(|(component, remaining_components)| {
+                    wildcard_matches(segment, component)
+                        && segments_match(remaining_segments, remaining_components)
+                })
+        }
+    }
+}
+
+fn wildcard_matches(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', remaining_pattern)) => {
+            (0..=name.len()).any(|skipped| wildcard_matches(remaining_pattern, &name[skipped..]))
+        }
+        Some(('?', remaining_pattern)) => {
+            !name.is_empty() && wildcard_matches(remaining_pattern, &name[1..])
+        }
+        Some(('[', remaining_pattern)) => match char_class_end(remaining_pattern) {
+            Some(end) => name
+                .split_first()
+                .is_some_and(|(candidate, remaining_name)| {
+                    char_class_matches(&remaining_pattern[..end], *candidate)
+                        && wildcard_matches(&remaining_pattern[end + 1..], remaining_name)
+                }),
+            None => literal_matches('[', remaining_pattern, name),
+        },
+        Some(('\\', [escaped, remaining_pattern @ ..])) => {
+            literal_matches(*escaped, remaining_pattern, name)
+        }
+        Some((literal, remaining_pattern)) => literal_matches(*literal, remaining_pattern, name),
+    }
+}
+
+fn literal_matches(literal: char, remaining_pattern: &[char], name: &[char]) -> bool {
+    name.split_first()
+        .is_some_and(|(candidate, remaining_name)| {
+            *candidate == literal && wildcard_matches(remaining_pattern, remaining_name)
+        })
+}
+
+fn char_class_end(class: &[char]) -> Option<usize> {
+    let first_item = usize::from(matches!(class.first(), Some('!' | '^')));
+    class
+        .iter()
+        .skip(first_item + 1)
+        .position(|character| *character == ']')
+        .map(|position| position + first_item + 1)
+}
+
+fn char_class_matches(class: &[char], candidate: char) -> bool {
+    let (negated, items) = match class.split_first() {
+        Some(('!' | '^', items)) => (true, items),
+        _ => (false, class),
+    };
+
+    let mut matched = false;
+    let mut index = 0;
+    while index < items.len() {
+        if index + 2 < items.len() && items[index + 1] == '-' {
+            matched |= (items[index]..=items[index + 2]).contains(&candidate);
+            index += 3;
+        } else {
+            matched |= items[index] == candidate;
+            index += 1;
+        }
+    }
+
+    matched != negated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DocIgnoreRules;
+    use std::path::{Path, PathBuf};
+
+    fn rules(patterns: &[&str]) -> DocIgnoreRules {
+        DocIgnoreRules::load(
+            Path::new("/tmp/coda-ignore-rules"),
+            &[],
+            &patterns
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect::<Vec<String>>(),
+        )
+    }
+
+    fn path(relative_path: &str) -> PathBuf {
+        Path::new("/tmp/coda-ignore-rules").join(relative_path)
+    }
+
+    #[test]
+    fn matches_unanchored_names_at_any_depth() {
+        let rules = rules(&["node_modules/", "*.draft.md", "notes-?.md"]);
+
+        assert!(rules.is_ignored(&path("docs/node_modules/pkg/README.md"), false));
+        assert!(!rules.is_ignored(&path("docs/node_modules"), false));
+        assert!(rules.is_ignored(&path("docs/plans/idea.draft.md"), false));
+        assert!(rules.is_ignored(&path("docs/notes-1.md"), false));
+        assert!(!rules.is_ignored(&path("docs/notes-10.md"), false));
+        assert!(!rules.is_ignored(&path("docs/plans/idea.md"), false));
+    }
+
+    #[test]
+    fn anchors_patterns_with_separators_and_supports_double_star() {
+        let rules = rules(&["/docs/archive", "docs/**/scratch/*.md", "docs/[a-c]*.md"]);
+
+        assert!(rules.is_ignored(&path("docs/archive/old.md"), false));
+        assert!(!rules.is_ignored(&path("docs/plans/archive/old.md"), false));
+        assert!(rules.is_ignored(&path("docs/scratch/todo.md"), false));
+        assert!(rules.is_ignored(&path("docs/plans/active/scratch/todo.md"), false));
+        assert!(rules.is_ignored(&path("docs/beliefs.md"), false));
+        assert!(!rules.is_ignored(&path("docs/PRD.md"), false));
+    }
+
+    #[test]
+    fn lets_later_negations_reinclude_files() {
+        let rules = rules(&["*.md", "!PRD.md", "private/", "!docs/private/keep.md"]);
+
+        assert!(rules.is_ignored(&path("docs/notes.md"), false));
+        assert!(!rules.is_ignored(&path("docs/PRD.md"), false));
+        assert!(rules.is_ignored(&path("docs/private/keep.md"), false));
+    }
+}
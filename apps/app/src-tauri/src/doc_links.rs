@@ -0,0 +1,449 @@
+use crate::doc_markdown::extract_markdown_links;
+use crate::plan_viewer::{
+    is_doc_file, load_doc_documents_from_roots, resolve_canonical_roots, DocDocument,
+};
+use crate::project_registry::{resolve_scoped_doc_id, DocsRoot};
+use crate::project_runtime::ProjectRegistryState;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+use tauri::State;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocLinkOrigin {
+    Body,
+    Frontmatter,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocLink {
+    pub source_doc_id: String,
+    pub raw_target: String,
+    pub target_doc_id: Option<String>,
+    pub target_path: Option<String>,
+    pub anchor: Option<String>,
+    pub text: Option<String>,
+    pub line: Option<usize>,
+    pub origin: DocLinkOrigin,
+    pub is_broken: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DocLinkGraph {
+    doc_ids: BTreeSet<String>,
+    links: Vec<DocLink>,
+}
+
+#[tauri::command]
+pub fn list_doc_outgoing_links(
+    doc_id: String,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<Vec<DocLink>, String> {
+    list_active_project_doc_links(&state, &doc_id, DocLinkGraph::outgoing_links)
+}
+
+#[tauri::command]
+pub fn list_doc_backlinks(
+    doc_id: String,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<Vec<DocLink>, String> {
+    list_active_project_doc_links(&state, &doc_id, DocLinkGraph::backlinks)
+}
+
+#[tauri::command]
+pub fn list_doc_broken_links(
+    doc_id: String,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<Vec<DocLink>, String> {
+    list_active_project_doc_links(&state, &doc_id, DocLinkGraph::broken_links)
+}
+
+fn list_active_project_doc_links(
+    state: &ProjectRegistryState,
+    doc_id: &str,
+    select_links: fn(&DocLinkGraph, &str) -> Vec<DocLink>,
+) -> Result<Vec<DocLink>, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, local_doc_id) = active_project.resolve_docs_root(doc_id)?;
+    let doc_id = docs_root.scoped_doc_id(&local_doc_id);
+    let graph = build_doc_link_graph_from_root(
+        &active_project.root_path,
+        &active_project.docs_roots,
+        &active_project.docs_exclude,
+    )?;
+    graph.ensure_doc_exists(&doc_id)?;
+
+    Ok(select_links(&graph, &doc_id))
+}
+
+pub fn build_doc_link_graph_from_root(
+    workspace_root: &Path,
+    docs_roots: &[DocsRoot],
+    docs_exclude: &[String],
+) -> Result<DocLinkGraph, String> {
+    let documents = load_doc_documents_from_roots(workspace_root, docs_roots, docs_exclude)?;
+    build_doc_link_graph_for_documents(workspace_root, docs_roots, &documents)
+}
+
+pub fn build_doc_link_graph_for_documents(
+    workspace_root: &Path,
+    docs_roots: &[DocsRoot],
+    documents: &[DocDocument],
+) -> Result<DocLinkGraph, String> {
+    if documents.is_empty() {
+        return Ok(DocLinkGraph::default());
+    }
+
+    let (workspace_root_canonical, docs_roots_canonical) =
+        resolve_canonical_docs_roots(workspace_root, docs_roots)?;
+
+    Ok(DocLinkGraph::build(
+        &workspace_root_canonical,
+        &docs_roots_canonical,
+        documents,
+    ))
+}
+
+pub fn resolve_canonical_docs_roots(
+    workspace_root: &Path,
+    docs_roots: &[DocsRoot],
+) -> Result<(PathBuf, Vec<DocsRoot>), String> {
+    let mut workspace_root_canonical = workspace_root.to_path_buf();
+    let mut docs_roots_canonical = Vec::new();
+
+    for docs_root in docs_roots
+        .iter()
+        .filter(|docs_root| docs_root.path.exists())
+    {
+        let (workspace_root_path, docs_root_path) =
+            resolve_canonical_roots(workspace_root, &docs_root.path)?;
+        workspace_root_canonical = workspace_root_path;
+        docs_roots_canonical.push(DocsRoot {
+            namespace: docs_root.namespace.clone(),
+            path: docs_root_path,
+        });
+    }
+
+    Ok((workspace_root_canonical, docs_roots_canonical))
+}
+
+pub fn resolve_body_link_doc_id(
+    workspace_root: &Path,
+    docs_roots: &[DocsRoot],
+    source_doc_id: &str,
+    destination: &str,
+) -> Option<(String, Option<String>)> {
+    let (docs_root, local_doc_id) = resolve_scoped_doc_id(docs_roots, source_doc_id)?;
+    let source_path = docs_root.path.join(local_doc_id);
+    let source_dir = source_path.parent().unwrap_or(&docs_root.path);
+    let (target_path, anchor) =
+        resolve_body_target(workspace_root, &source_path, source_dir, destination)?;
+
+    if !is_doc_file(&target_path) {
+        return None;
+    }
+
+    let doc_id = scoped_doc_id_for_path(docs_roots, &target_path)?;
+    Some((doc_id, anchor))
+}
+
+impl DocLinkGraph {
+    pub fn build(
+        workspace_root: &Path,
+        docs_roots: &[DocsRoot],
+        documents: &[DocDocument],
+    ) -> Self {
+        let doc_ids = documents
+            .iter()
+            .map(|document| document.id.clone())
+            .collect::<BTreeSet<String>>();
+        let resolver = DocLinkResolver {
+            workspace_root,
+            docs_roots,
+            doc_ids: &doc_ids,
+        };
+        let mut links = Vec::new();
+
+        for document in documents {
+            let Some((docs_root, local_doc_id)) = resolve_scoped_doc_id(docs_roots, &document.id)
+            else {
+                continue;
+            };
+            let source_path = docs_root.path.join(local_doc_id);
+            let source_dir = source_path.parent().unwrap_or(&docs_root.path);
+
+            for related in &document.related {
+                if let Some(target) = resolve_related_target(
+                    workspace_root,
+                    docs_roots,
+                    &docs_root.path,
+                    source_dir,
+                    related,
+                ) {
+                    links.push(resolver.link(
+                        document,
+                        related,
+                        target,
+                        DocLinkOrigin::Frontmatter,
+                    ));
+                }
+            }
+
+            for markdown_link in extract_markdown_links(&document.markdown_body) {
+                let Some(target) = resolve_body_target(
+                    workspace_root,
+                    &source_path,
+                    source_dir,
+                    &markdown_link.destination,
+                ) else {
+                    continue;
+                };
+
+                let mut link = resolver.link(
+                    document,
+                    &markdown_link.destination,
+                    target,
+                    DocLinkOrigin::Body,
+                );
+                link.text = Some(markdown_link.text).filter(|text| !text.is_empty());
+                link.line = Some(markdown_link.line + document.body_line_offset);
+                links.push(link);
+            }
+        }
+
+        Self { doc_ids, links }
+    }
+
+    pub fn contains_doc(&self, doc_id: &str) -> bool {
+        self.doc_ids.contains(doc_id)
+    }
+
+    pub fn links(&self) -> &[DocLink] {
+        &self.links
+    }
+
+    pub fn ensure_doc_exists(&self, doc_id: &str) -> Result<(), String> {
+        if self.contains_doc(doc_id) {
+            return Ok(());
+        }
+
+        Err(format!("document not found: {doc_id}"))
+    }
+
+    pub fn outgoing_links(&self, doc_id: &str) -> Vec<DocLink> {
+        self.links
+            .iter()
+            .filter(|link| link.source_doc_id == doc_id)
+            .cloned()
+            .collect()
+    }
+
+    pub fn backlinks(&self, doc_id: &str) -> Vec<DocLink> {
+        let mut backlinks = self
+            .links
+            .iter()
+            .filter(|link| {
+                link.source_doc_id != doc_id && link.target_doc_id.as_deref() == Some(doc_id)
+            })
+            .cloned()
+            .collect::<Vec<DocLink>>();
+
+        backlinks.sort_by(|left, right| {
+            left.source_doc_id
+                .cmp(&right.source_doc_id)
+                .then_with(|| left.line.cmp(&right.line))
+        });
+        backlinks
+    }
+
+    pub fn broken_links(&self, doc_id: &str) -> Vec<DocLink> {
+        self.links
+            .iter()
+            .filter(|link| link.source_doc_id == doc_id && link.is_broken)
+            .cloned()
+            .collect()
+    }
+}
+
+struct DocLinkResolver<'a> {
+    workspace_root: &'a Path,
+    docs_roots: &'a [DocsRoot],
+    doc_ids: &'a BTreeSet<String>,
+}
+
+impl DocLinkResolver<'_> {
+    fn link(
+        &self,
+        source: &DocDocument,
+        raw_target: &str,
+        (target_path, anchor): (PathBuf, Option<String>),
+        origin: DocLinkOrigin,
+    ) -> DocLink {
+        let target_doc_id = scoped_doc_id_for_path(self.docs_roots, &target_path)
+            .filter(|doc_id| self.doc_ids.contains(doc_id));
+        let workspace_relative_path = target_path
+            .strip_prefix(self.workspace_root)
+            .ok()
+            .map(path_to_slash_string);
+
+        let is_broken = match (&target_doc_id, &workspace_relative_path) {
+            (Some(_), _) => false,
+            (None, Some(_)) => !target_path.exists(),
+            (None, None) => true,
+        };
+
+        DocLink {
+            source_doc_id: source.id.clone(),
+            raw_target: raw_target.to_string(),
+            target_doc_id,
+            target_path: workspace_relative_path,
+            anchor,
+            text: None,
+            line: None,
+            origin,
+            is_broken,
+        }
+    }
+}
+
+fn resolve_body_target(
+    workspace_root: &Path,
+    source_path: &Path,
+    source_dir: &Path,
+    destination: &str,
+) -> Option<(PathBuf, Option<String>)> {
+    let (path_part, anchor) = split_link_destination(destination)?;
+
+    if path_part.is_empty() {
+        return Some((source_path.to_path_buf(), anchor));
+    }
+
+    let target_path = match path_part.strip_prefix('/') {
+        Some(workspace_relative) => workspace_root.join(workspace_relative),
+        None => source_dir.join(&path_part),
+    };
+
+    Some((normalize_lexically(&target_path), anchor))
+}
+
+fn resolve_related_target(
+    workspace_root: &Path,
+    docs_roots: &[DocsRoot],
+    docs_root: &Path,
+    source_dir: &Path,
+    related: &str,
+) -> Option<(PathBuf, Option<String>)> {
+    let (path_part, anchor) = split_link_destination(related)?;
+
+    if path_part.is_empty() {
+        return None;
+    }
+
+    if path_part.starts_with("./") || path_part.starts_with("../") {
+        return Some((normalize_lexically(&source_dir.join(&path_part)), anchor));
+    }
+
+    let workspace_candidate =
+        normalize_lexically(&workspace_root.join(path_part.trim_start_matches('/')));
+    if docs_roots
+        .iter()
+        .any(|docs_root| workspace_candidate.starts_with(&docs_root.path))
+    {
+        return Some((workspace_candidate, anchor));
+    }
+
+    Some((normalize_lexically(&docs_root.join(&path_part)), anchor))
+}
+
+fn scoped_doc_id_for_path(docs_roots: &[DocsRoot], path: &Path) -> Option<String> {
+    docs_roots.iter().find_map(|docs_root| {
+        path.strip_prefix(&docs_root.path)
+            .ok()
+            .map(|relative_path| docs_root.scoped_doc_id(&path_to_slash_string(relative_path)))
+    })
+}
+
+fn split_link_destination(destination: &str) -> Option<(String, Option<String>)> {
+    let trimmed = destination.trim();
+    if trimmed.is_empty() || trimmed.starts_with("//") || has_url_scheme(trimmed) {
+        return None;
+    }
+
+    let (without_anchor, anchor) = match trimmed.split_once('#') {
+        Some((path, anchor)) => (path, Some(decode_percent_escapes(anchor))),
+        None => (trimmed, None),
+    };
+    let path_part = without_anchor
+        .split_once('?')
+        .map(|(path, _)| path)
+        .unwrap_or(without_anchor);
+
+    Some((
+        decode_percent_escapes(path_part),
+        anchor.filter(|value| !value.is_empty()),
+    ))
+}
+
+fn has_url_scheme(destination: &str) -> bool {
+    let Some((scheme, _)) = destination.split_once(':') else {
+        return false;
+    };
+
+    let mut characters = scheme.chars();
+    characters
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic())
+        && characters.all(|character| {
+            character.is_ascii_alphanumeric() || matches!(character, '+' | '-' | '.')
+        })
+}
+
+fn decode_percent_escapes(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] == b'%'
+            && index + 2 < bytes.len()
+            && bytes[index + 1].is_ascii_hexdigit()
+            && bytes[index + 2].is_ascii_hexdigit()
+        {
+            let hex = std::str::from_utf8(&bytes[(index + 1)..(index + 3)]).unwrap_or("00");
+            decoded.push(u8::from_str_radix(hex, 16).unwrap_or_default());
+            index += 3;
+            continue;
+        }
+
+        decoded.push(bytes[index]);
+        index += 1;
+    }
+
+    String::from_utf8(decoded).unwrap_or_else(|_| value.to_string())
+}
+
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+
+    normalized
+}
+
+fn path_to_slash_string(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+#[cfg(test)]
+#[path = "doc_links_tests.rs"]
+mod tests;
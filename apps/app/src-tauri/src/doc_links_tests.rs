@@ -0,0 +1,142 @@
+use super::{build_doc_link_graph_from_root, DocLinkOrigin};
+use crate::project_registry::DocsRoot;
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
+use std::path::Path;
+
+fn primary_docs_root(workspace: &Path) -> Vec<DocsRoot> {
+    vec![DocsRoot {
+        namespace: None,
+        path: workspace.join("docs"),
+    }]
+}
+
+fn seed_knowledge_base(workspace: &Path) {
+    let docs_root = workspace.join("docs");
+
+    write_doc_file(
+        &docs_root.join("plans/active/2026-02-19-feat-links-plan.md"),
+        "---\ntitle: Links Plan\ndate: 2026-02-19\nstatus: active\n---\n\n## Goal\n\nSee [beliefs](../../design-docs/core-beliefs.md#principles) and [missing](./gone.md).\n",
+    );
+    write_doc_file(
+        &docs_root.join("solutions/2026-02-19-links.md"),
+        "---\ntitle: Links Solution\ndate: 2026-02-19\nrelated_plan: \"docs/plans/active/2026-02-19-feat-links-plan.md\"\n---\n\n## Solution\n\nBuilt from [the plan](/docs/plans/active/2026-02-19-feat-links-plan.md) and [docs](https://example.com).\n",
+    );
+    write_doc_file(
+        &docs_root.join("design-docs/core-beliefs.md"),
+        "---\ntitle: Core Beliefs\ndate: 2026-02-14\n---\n\n## Principles\n\nBody\n",
+    );
+}
+
+#[test]
+fn resolves_outgoing_links_relative_to_source_document() {
+    let workspace = create_temp_workspace("doc-links");
+    seed_knowledge_base(&workspace);
+
+    let graph = build_doc_link_graph_from_root(&workspace, &primary_docs_root(&workspace), &[])
+        .expect("link graph should build");
+    let outgoing = graph.outgoing_links("plans/active/2026-02-19-feat-links-plan.md");
+
+    assert_eq!(outgoing.len(), 2);
+    assert_eq!(
+        outgoing[0].target_doc_id.as_deref(),
+        Some("design-docs/core-beliefs.md")
+    );
+    assert_eq!(outgoing[0].anchor.as_deref(), Some("principles"));
+    assert_eq!(outgoing[0].text.as_deref(), Some("beliefs"));
+    assert_eq!(outgoing[0].line, Some(9));
+    assert!(!outgoing[0].is_broken);
+
+    assert_eq!(outgoing[1].target_doc_id, None);
+    assert_eq!(
+        outgoing[1].target_path.as_deref(),
+        Some("docs/plans/active/gone.md")
+    );
+    assert!(outgoing[1].is_broken);
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn collects_backlinks_from_body_and_related_frontmatter() {
+    let workspace = create_temp_workspace("doc-links");
+    seed_knowledge_base(&workspace);
+
+    let graph = build_doc_link_graph_from_root(&workspace, &primary_docs_root(&workspace), &[])
+        .expect("link graph should build");
+    let backlinks = graph.backlinks("plans/active/2026-02-19-feat-links-plan.md");
+
+    assert_eq!(backlinks.len(), 2);
+    assert!(backlinks
+        .iter()
+        .all(|link| link.source_doc_id == "solutions/2026-02-19-links.md"));
+    assert!(backlinks
+        .iter()
+        .any(|link| link.origin == DocLinkOrigin::Frontmatter));
+    assert!(backlinks
+        .iter()
+        .any(|link| link.origin == DocLinkOrigin::Body));
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn reports_only_broken_links_for_requested_document() {
+    let workspace = create_temp_workspace("doc-links");
+    seed_knowledge_base(&workspace);
+
+    let graph = build_doc_link_graph_from_root(&workspace, &primary_docs_root(&workspace), &[])
+        .expect("link graph should build");
+
+    let broken = graph.broken_links("plans/active/2026-02-19-feat-links-plan.md");
+    assert_eq!(broken.len(), 1);
+    assert_eq!(broken[0].raw_target, "./gone.md");
+
+    assert!(graph
+        .broken_links("solutions/2026-02-19-links.md")
+        .is_empty());
+    assert!(graph.ensure_doc_exists("plans/unknown.md").is_err());
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn resolves_links_and_backlinks_across_docs_roots() {
+    let workspace = create_temp_workspace("doc-links");
+    seed_knowledge_base(&workspace);
+    write_doc_file(
+        &workspace.join("adr/0001-storage.md"),
+        "---\ntitle: Storage\ndate: 2026-02-19\nrelated: [docs/design-docs/core-beliefs.md]\n---\n\n## Decision\n\nFollows [the plan](../docs/plans/active/2026-02-19-feat-links-plan.md).\n",
+    );
+    write_doc_file(
+        &workspace.join("docs/design-docs/storage.md"),
+        "---\ntitle: Storage Notes\ndate: 2026-02-19\n---\n\nDecided in [ADR 1](/adr/0001-storage.md#decision).\n",
+    );
+    let mut docs_roots = primary_docs_root(&workspace);
+    docs_roots.push(DocsRoot {
+        namespace: Some("adr".to_string()),
+        path: workspace.join("adr"),
+    });
+
+    let graph = build_doc_link_graph_from_root(&workspace, &docs_roots, &[])
+        .expect("link graph should build");
+
+    let outgoing = graph.outgoing_links("adr:0001-storage.md");
+    assert_eq!(outgoing.len(), 2);
+    assert!(outgoing.iter().all(|link| !link.is_broken));
+    assert!(graph
+        .backlinks("design-docs/core-beliefs.md")
+        .iter()
+        .any(|link| link.source_doc_id == "adr:0001-storage.md"
+            && link.origin == DocLinkOrigin::Frontmatter));
+    assert!(graph
+        .backlinks("plans/active/2026-02-19-feat-links-plan.md")
+        .iter()
+        .any(|link| link.source_doc_id == "adr:0001-storage.md"));
+
+    let backlinks = graph.backlinks("adr:0001-storage.md");
+    assert_eq!(backlinks.len(), 1);
+    assert_eq!(backlinks[0].source_doc_id, "design-docs/storage.md");
+    assert_eq!(backlinks[0].anchor.as_deref(), Some("decision"));
+
+    teardown_workspace(&workspace);
+}
@@ -0,0 +1,296 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownLink {
+    pub destination: String,
+    pub text: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownHeading {
+    pub level: u8,
+    pub text: String,
+    pub slug: String,
+    pub line: usize,
+    pub start_offset: usize,
+    pub end_offset: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownTask {
+    pub line: usize,
+    pub checked: bool,
+    pub text: String,
+}
+
+pub fn markdown_parser_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_FOOTNOTES
+}
+
+pub fn extract_markdown_links(markdown: &str) -> Vec<MarkdownLink> {
+    let mut links = Vec::new();
+    let mut open_link: Option<MarkdownLink> = None;
+
+    for (event, range) in Parser::new_ext(markdown, markdown_parser_options()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                open_link = Some(MarkdownLink {
+                    destination: dest_url.to_string(),
+                    text: String::new(),
+                    line: line_number_at(markdown, range.start),
+                });
+            }
+            Event::End(TagEnd::Link) => {
+                if let Some(mut link) = open_link.take() {
+                    link.text = link.text.trim().to_string();
+                    links.push(link);
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(link) = open_link.as_mut() {
+                    link.text.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    links
+}
+
+pub fn extract_heading_outline(markdown: &str) -> Vec<MarkdownHeading> {
+    let mut headings = Vec::new();
+    let mut seen_slugs = BTreeMap::new();
+    let mut open_heading: Option<MarkdownHeading> = None;
+
+    for (event, range) in Parser::new_ext(markdown, markdown_parser_options()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                open_heading = Some(MarkdownHeading {
+                    level: level as u8,
+                    text: String::new(),
+                    slug: String::new(),
+                    line: line_number_at(markdown, range.start),
+                    start_offset: range.start,
+                    end_offset: range.end,
+                });
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(mut heading) = open_heading.take() {
+                    heading.text = heading.text.trim().to_string();
+                    heading.slug = unique_heading_slug(&heading.text, &mut seen_slugs);
+                    headings.push(heading);
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading) = open_heading.as_mut() {
+                    heading.text.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    headings
+}
+
+pub fn extract_task_items(markdown: &str) -> Vec<MarkdownTask> {
+    Parser::new_ext(markdown, markdown_parser_options())
+        .into_offset_iter()
+        .filter_map(|(event, range)| match event {
+            Event::TaskListMarker(checked) => {
+                let line_end = markdown[range.end..]
+                    .find('\n')
+                    .map(|index| range.end + index)
+                    .unwrap_or(markdown.len());
+
+                Some(MarkdownTask {
+                    line: line_number_at(markdown, range.start),
+                    checked,
+                    text: markdown[range.end..line_end].trim().to_string(),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn heading_section<'a>(
+    markdown: &str,
+    outline: &'a [MarkdownHeading],
+    slug: &str,
+) -> Option<(&'a MarkdownHeading, Range<usize>)> {
+    let index = outline.iter().position(|heading| heading.slug == slug)?;
+    let heading = &outline[index];
+    let section_end = outline[(index + 1)..]
+        .iter()
+        .find(|next| next.level <= heading.level)
+        .map(|next| next.start_offset)
+        .unwrap_or(markdown.len());
+
+    Some((heading, heading.start_offset..section_end))
+}
+
+pub fn slugify_heading(text: &str) -> String {
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|character| {
+            if character.is_alphanumeric() || character == '-' || character == '_' {
+                Some(character)
+            } else if character.is_whitespace() {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn unique_heading_slug(text: &str, seen_slugs: &mut BTreeMap<String, usize>) -> String {
+    let base_slug = slugify_heading(text);
+    let occurrences = seen_slugs.entry(base_slug.clone()).or_insert(0);
+    let slug = if *occurrences == 0 {
+        base_slug
+    } else {
+        format!("{base_slug}-{occurrences}")
+    };
+    *occurrences += 1;
+    slug
+}
+
+pub fn line_number_at(markdown: &str, byte_offset: usize) -> usize {
+    let end = byte_offset.min(markdown.len());
+    markdown.as_bytes()[..end]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count()
+        + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        extract_heading_outline, extract_markdown_links, extract_task_items, heading_section,
+        slugify_heading, MarkdownLink, MarkdownTask,
+    };
+
+    #[test]
+    fn extracts_inline_and_reference_links_with_line_numbers() {
+        let links = extract_markdown_links(
+            "# Title\n\nSee [the plan](../plans/a.md#goal).\n\n[ref][adr]\n\n[adr]: ./ADR-001.md\n",
+        );
+
+        assert_eq!(
+            links,
+            vec![
+                MarkdownLink {
+                    destination: "../plans/a.md#goal".to_string(),
+                    text: "the plan".to_string(),
+                    line: 3,
+                },
+                MarkdownLink {
+                    destination: "./ADR-001.md".to_string(),
+                    text: "ref".to_string(),
+                    line: 5,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn slugifies_headings_like_github_with_duplicate_suffixes() {
+        let outline = extract_heading_outline(
+            "## Root Cause\n\n## Root Cause\n\n### `DocSummary` fields & flags\n",
+        );
+
+        assert_eq!(
+            outline
+                .iter()
+                .map(|heading| heading.slug.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["root-cause", "root-cause-1", "docsummary-fields--flags"]
+        );
+        assert_eq!(
+            slugify_heading(" ADR-006: Human-in-the-Loop "),
+            "adr-006-human-in-the-loop"
+        );
+    }
+
+    #[test]
+    fn builds_outline_with_offsets_and_nested_section_ranges() {
+        let markdown =
+            "# Plan\n\n## Goal\n\nShip it.\n\n### Detail\n\nMore.\n\n## Validation\n\nRun tests.\n";
+        let outline = extract_heading_outline(markdown);
+
+        assert_eq!(
+            outline
+                .iter()
+                .map(|heading| (heading.level, heading.slug.as_str(), heading.line))
+                .collect::<Vec<(u8, &str, usize)>>(),
+            vec![
+                (1, "plan", 1),
+                (2, "goal", 3),
+                (3, "detail", 7),
+                (2, "validation", 11)
+            ]
+        );
+        assert_eq!(
+            &markdown[outline[1].start_offset..outline[1].end_offset],
+            "## Goal\n"
+        );
+
+        let (heading, range) =
+            heading_section(markdown, &outline, "goal").expect("goal section should exist");
+        assert_eq!(heading.text, "Goal");
+        assert_eq!(
+            &markdown[range],
+            "## Goal\n\nShip it.\n\n### Detail\n\nMore.\n\n"
+        );
+        assert!(heading_section(markdown, &outline, "missing").is_none());
+    }
+
+    #[test]
+    fn extracts_nested_task_items_with_state_and_line_numbers() {
+        let tasks = extract_task_items(
+            "## Approach\n\n1. **Step**\n   - [x] Action: build\n   - [ ] Exit criteria: tests pass\n\n- [X] Done\n- not a task\n\n```md\n- [ ] in code\n```\n",
+        );
+
+        assert_eq!(
+            tasks,
+            vec![
+                MarkdownTask {
+                    line: 4,
+                    checked: true,
+                    text: "Action: build".to_string(),
+                },
+                MarkdownTask {
+                    line: 5,
+                    checked: false,
+                    text: "Exit criteria: tests pass".to_string(),
+                },
+                MarkdownTask {
+                    line: 7,
+                    checked: true,
+                    text: "Done".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_link_syntax_inside_code_blocks() {
+        let links = extract_markdown_links("```md\n[not a link](./nope.md)\n```\n");
+
+        assert!(links.is_empty());
+    }
+}
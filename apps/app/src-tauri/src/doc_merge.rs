@@ -0,0 +1,250 @@
+const CONFLICT_START_MARKER: &str = "<<<<<<< yours";
+const CONFLICT_SEPARATOR_MARKER: &str = "=======";
+const CONFLICT_END_MARKER: &str = ">>>>>>> current";
+
+#[derive(Debug, Clone)]
+pub struct TextMerge {
+    pub text: String,
+    pub has_conflicts: bool,
+}
+
+pub fn merge_text(base: &str, yours: &str, current: &str) -> TextMerge {
+    let base_lines = base.split_inclusive('\n').collect::<Vec<&str>>();
+    let your_lines = yours.split_inclusive('\n').collect::<Vec<&str>>();
+    let current_lines = current.split_inclusive('\n').collect::<Vec<&str>>();
+
+    let your_matches = matching_lines(&base_lines, &your_lines);
+    let current_matches = matching_lines(&base_lines, &current_lines);
+
+    let mut merge = TextMerge {
+        text: String::new(),
+        has_conflicts: false,
+    };
+    let (mut base_index, mut your_index, mut current_index) = (0, 0, 0);
+
+    loop {
+        let mut stable_length = 0;
+        while base_index + stable_length < base_lines.len()
+            && your_matches[base_index + stable_length] == Some(your_index + stable_length)
+            && current_matches[base_index + stable_length] == Some(current_index + stable_length)
+        {
+            stable_length += 1;
+        }
+
+        if stable_length > 0 {
+            merge
+                .text
+                .push_str(&base_lines[base_index..(base_index + stable_length)].concat());
+            base_index += stable_length;
+            your_index += stable_length;
+            current_index += stable_length;
+            continue;
+        }
+
+        let next_anchor = (base_index..base_lines.len())
+            .find_map(|index| Some((index, your_matches[index]?, current_matches[index]?)));
+        let (next_base, next_yours, next_current) =
+            next_anchor.unwrap_or((base_lines.len(), your_lines.len(), current_lines.len()));
+
+        merge_chunk(
+            &mut merge,
+            &base_lines[base_index..next_base],
+            &your_lines[your_index..next_yours],
+            &current_lines[current_index..next_current],
+        );
+
+        if next_anchor.is_none() {
+            return merge;
+        }
+
+        base_index = next_base;
+        your_index = next_yours;
+        current_index = next_current;
+    }
+}
+
+fn merge_chunk(merge: &mut TextMerge, base: &[&str], yours: &[&str], current: &[&str]) {
+    if yours == base || yours == current {
+        merge.text.push_str(&current.concat());
+        return;
+    }
+
+    if current == base {
+        merge.text.push_str(&yours.concat());
+        return;
+    }
+
+    merge.has_conflicts = true;
+    push_marker_line(&mut merge.text, CONFLICT_START_MARKER);
+    push_conflict_side(&mut merge.text, yours);
+    push_marker_line(&mut merge.text, CONFLICT_SEPARATOR_MARKER);
+    push_conflict_side(&mut merge.text, current);
+    push_marker_line(&mut merge.text, CONFLICT_END_MARKER);
+}
+
+fn push_conflict_side(text: &mut String, lines: &[&str]) {
+    text.push_str(&lines.concat());
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+}
+
+fn push_marker_line(text: &mut String, marker: &str) {
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text.push_str(marker);
+    text.push('\n');
+}
+
+fn matching_lines(base: &[&str], other: &[&str]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; base.len()];
+
+    let prefix_length = base
+        .iter()
+        .zip(other)
+        .take_while(|(left, right)| left == right)
+        .count();
+    let suffix_length = base[prefix_length..]
+        .iter()
+        .rev()
+        .zip(other[prefix_length..].iter().rev())
+        .take_while(|(left, right)| left == right)
+        .count();
+
+    for (index, matched) in matches.iter_mut().enumerate().take(prefix_length) {
+        *matched = Some(index);
+    }
+    for offset in 1..=suffix_length {
+        matches[base.len() - offset] = Some(other.len() - offset);
+    }
+
+    let base_middle = &base[prefix_length..(base.len() - suffix_length)];
+    let other_middle = &other[prefix_length..(other.len() - suffix_length)];
+    match_common_subsequence(
+        base_middle,
+        other_middle,
+        (prefix_length, prefix_length),
+        &mut matches,
+    );
+
+    matches
+}
+
+fn match_common_subsequence(
+    base: &[&str],
+    other: &[&str],
+    (base_start, other_start): (usize, usize),
+    matches: &mut [Option<usize>],
+) {
+    if base.is_empty() || other.is_empty() {
+        return;
+    }
+
+    if let [base_line] = base {
+        if let Some(other_offset) = other.iter().position(|line| line == base_line) {
+            matches[base_start] = Some(other_start + other_offset);
+        }
+        return;
+    }
+
+    let base_split = base.len() / 2;
+    let forward_lengths = common_subsequence_lengths(base[..base_split].iter(), other.iter());
+    let mut backward_lengths =
+        common_subsequence_lengths(base[base_split..].iter().rev(), other.iter().rev());
+    backward_lengths.reverse();
+
+    let other_split = (0..=other.len())
+        .max_by_key(|other_split| forward_lengths[*other_split] + backward_lengths[*other_split])
+        .unwrap_or_default();
+
+    match_common_subsequence(
+        &base[..base_split],
+        &other[..other_split],
+        (base_start, other_start),
+        matches,
+    );
+    match_common_subsequence(
+        &base[base_split..],
+        &other[other_split..],
+        (base_start + base_split, other_start + other_split),
+        matches,
+    );
+}
+
+fn common_subsequence_lengths<'a>(
+    base: impl Iterator<Item = &'a &'a str>,
+    other: impl Iterator<Item = &'a &'a str> + Clone,
+) -> Vec<usize> {
+    let mut lengths = vec![0_usize; other.clone().count() + 1];
+
+    for base_line in base {
+        let mut diagonal = 0;
+        for (other_offset, other_line) in other.clone().enumerate() {
+            let above = lengths[other_offset + 1];
+            lengths[other_offset + 1] = if base_line == other_line {
+                diagonal + 1
+            } else {
+                above.max(lengths[other_offset])
+            };
+            diagonal = above;
+        }
+    }
+
+    lengths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_text;
+
+    #[test]
+    fn merges_non_overlapping_edits_from_both_sides() {
+        let merge = merge_text(
+            "# Plan\n\nIntro text.\n\n## Steps\n\n- one\n- two\n",
+            "# Plan\n\nIntro text, fixed.\n\n## Steps\n\n- one\n- two\n",
+            "# Plan\n\nIntro text.\n\n## Steps\n\n- one\n- two\n- three\n",
+        );
+
+        assert!(!merge.has_conflicts);
+        assert_eq!(
+            merge.text,
+            "# Plan\n\nIntro text, fixed.\n\n## Steps\n\n- one\n- two\n- three\n"
+        );
+    }
+
+    #[test]
+    fn keeps_identical_edits_once() {
+        let merge = merge_text("a\nb\n", "a\nB\n", "a\nB\n");
+
+        assert!(!merge.has_conflicts);
+        assert_eq!(merge.text, "a\nB\n");
+    }
+
+    #[test]
+    fn merges_edits_at_both_ends_of_long_documents() {
+        let base = (0..2_000)
+            .map(|line| format!("line {line}\n"))
+            .collect::<String>();
+        let yours = base.replacen("line 0\n", "first line\n", 1);
+        let current = base.replacen("line 1999\n", "last line\n", 1);
+
+        let merge = merge_text(&base, &yours, &current);
+
+        assert!(!merge.has_conflicts);
+        assert!(merge.text.starts_with("first line\nline 1\n"));
+        assert!(merge.text.ends_with("line 1998\nlast line\n"));
+        assert_eq!(merge.text.lines().count(), 2_000);
+    }
+
+    #[test]
+    fn marks_overlapping_edits_as_conflicts() {
+        let merge = merge_text("a\nb\nc", "a\nmine\nc", "a\ntheirs\nc");
+
+        assert!(merge.has_conflicts);
+        assert_eq!(
+            merge.text,
+            "a\n<<<<<<< yours\nmine\n=======\ntheirs\n>>>>>>> current\nc"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::doc_format::{doc_format_for_path, BodySyntax};
+use crate::doc_links::{resolve_body_link_doc_id, resolve_canonical_docs_roots};
+use crate::doc_markdown::markdown_parser_options;
+use crate::plan_viewer::{get_doc_document_from_root, DocDocument};
+use crate::project_registry::{resolve_scoped_doc_id, DocsRoot};
+use crate::project_runtime::ProjectRegistryState;
+use pulldown_cmark::{html, CowStr, Event, Parser, Tag};
+use serde::Serialize;
+use std::borrow::Cow;
+use std::path::Path;
+use tauri::State;
+
+const DOC_LINK_SCHEME: &str = "coda-doc";
+const HEADING_TAGS: [&str; 6] = ["h1", "h2", "h3", "h4", "h5", "h6"];
+const CODE_LANGUAGE_CLASS_PREFIX: &str = "language-";
+const TABLE_ALIGNMENTS: [&str; 3] = ["left", "center", "right"];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderedDocHtml {
+    doc_id: String,
+    html: String,
+}
+
+#[tauri::command]
+pub fn render_doc_html(
+    doc_id: String,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<RenderedDocHtml, String> {
+    let active_project = state.active_project_context()?;
+    render_doc_html_from_root(
+        &active_project.root_path,
+        &active_project.docs_roots,
+        &doc_id,
+    )
+}
+
+pub fn render_doc_html_from_root(
+    workspace_root: &Path,
+    docs_roots: &[DocsRoot],
+    doc_id: &str,
+) -> Result<RenderedDocHtml, String> {
+    let (docs_root, local_doc_id) = resolve_scoped_doc_id(docs_roots, doc_id)
+        .ok_or_else(|| format!("document not found: {doc_id}"))?;
+    let document = get_doc_document_from_root(workspace_root, &docs_root.path, &local_doc_id)?
+        .scoped(docs_root);
+    let (workspace_root_canonical, docs_roots_canonical) =
+        resolve_canonical_docs_roots(workspace_root, docs_roots)?;
+
+    Ok(RenderedDocHtml {
+        html: render_document_html(&document, &workspace_root_canonical, &docs_roots_canonical),
+        doc_id: document.id,
+    })
+}
+
+fn render_document_html(
+    document: &DocDocument,
+    workspace_root: &Path,
+    docs_roots: &[DocsRoot],
+) -> String {
+    let mut heading_slugs = document.outline.iter().map(|heading| heading.slug.clone());
+    let markdown_source = doc_format_for_path(Path::new(&document.file_name))
+        .map_or(BodySyntax::Markdown, |format| format.body_syntax)
+        .markdown_source(&document.markdown_body);
+
+    let events =
+        Parser::new_ext(&markdown_source, markdown_parser_options()).map(|event| match event {
+            Event::Start(Tag::Heading {
+                level,
+                classes,
+                attrs,
+                ..
+            }) => Event::Start(Tag::Heading {
+                level,
+                id: heading_slugs.next().map(CowStr::from),
+                classes,
+                attrs,
+            }),
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }) => {
+                let dest_url =
+                    rewrite_doc_link_destination(document, workspace_root, docs_roots, dest_url);
+
+                Event::Start(Tag::Link {
+                    link_type,
+                    dest_url,
+                    title,
+                    id,
+                })
+            }
+            other => other,
+        });
+
+    let mut unsafe_html = String::new();
+    html::push_html(&mut unsafe_html, events);
+    sanitize_rendered_html(&unsafe_html)
+}
+
+fn rewrite_doc_link_destination<'a>(
+    document: &DocDocument,
+    workspace_root: &Path,
+    docs_roots: &[DocsRoot],
+    dest_url: CowStr<'a>,
+) -> CowStr<'a> {
+    match resolve_body_link_doc_id(workspace_root, docs_roots, &document.id, &dest_url) {
+        Some((doc_id, anchor)) => CowStr::from(doc_link_href(&doc_id, anchor.as_deref())),
+        None => dest_url,
+    }
+}
+
+fn doc_link_href(doc_id: &str, anchor: Option<&str>) -> String {
+    match anchor {
+        Some(anchor) => format!("{DOC_LINK_SCHEME}:{doc_id}#{anchor}"),
+        None => format!("{DOC_LINK_SCHEME}:{doc_id}"),
+    }
+}
+
+fn sanitize_rendered_html(unsafe_html: &str) -> String {
+    let mut builder = ammonia::Builder::default();
+
+    builder
+        .add_tags(["input"])
+        .add_tag_attributes("input", ["type", "checked", "disabled"])
+        .add_tag_attributes("code", ["class"])
+        .add_tag_attributes("th", ["style"])
+        .add_tag_attributes("td", ["style"])
+        .add_url_schemes([DOC_LINK_SCHEME])
+        .attribute_filter(filter_rendered_attribute);
+
+    for heading_tag in HEADING_TAGS {
+        builder.add_tag_attributes(heading_tag, ["id"]);
+    }
+
+    builder.clean(unsafe_html).to_string()
+}
+
+fn filter_rendered_attribute<'u>(
+    element: &str,
+    attribute: &str,
+    value: &'u str,
+) -> Option<Cow<'u, str>> {
+    match (element, attribute) {
+        ("input", "type") => (value == "checkbox").then_some(Cow::Borrowed(value)),
+        ("code", "class") => {
+            let language_classes = value
+                .split_whitespace()
+                .filter(|class| class.starts_with(CODE_LANGUAGE_CLASS_PREFIX))
+                .collect::<Vec<&str>>();
+
+            (!language_classes.is_empty()).then(|| Cow::Owned(language_classes.join(" ")))
+        }
+        ("th" | "td", "style") => is_table_alignment_style(value).then_some(Cow::Borrowed(value)),
+        _ => Some(Cow::Borrowed(value)),
+    }
+}
+
+fn is_table_alignment_style(value: &str) -> bool {
+    let declaration = value.trim();
+    let declaration = declaration.strip_suffix(';').unwrap_or(declaration);
+
+    declaration
+        .strip_prefix("text-align:")
+        .is_some_and(|alignment| TABLE_ALIGNMENTS.contains(&alignment.trim()))
+}
+
+#[cfg(test)]
+#[path = "doc_render_tests.rs"]
+mod tests;
@@ -0,0 +1,124 @@
+use super::render_doc_html_from_root;
+use crate::project_registry::DocsRoot;
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
+
+fn render(body: &str) -> String {
+    render_in_namespace(body, None)
+}
+
+fn render_in_namespace(body: &str, namespace: Option<&str>) -> String {
+    let workspace = create_temp_workspace("doc-render");
+    let docs_root = workspace.join("docs");
+
+    write_doc_file(
+        &docs_root.join("design-docs/core-beliefs.md"),
+        "---\ntitle: Core Beliefs\ndate: 2026-02-14\n---\n\n## Principles\n",
+    );
+    write_doc_file(
+        &docs_root.join("design-docs/index.md"),
+        &format!("---\ntitle: Index\ndate: 2026-02-14\n---\n\n{body}"),
+    );
+
+    let docs_root = DocsRoot {
+        namespace: namespace.map(str::to_string),
+        path: docs_root,
+    };
+
+    let rendered = render_doc_html_from_root(
+        &workspace,
+        std::slice::from_ref(&docs_root),
+        &docs_root.scoped_doc_id("design-docs/index.md"),
+    )
+    .expect("document should render");
+
+    teardown_workspace(&workspace);
+    rendered.html
+}
+
+#[test]
+fn renders_gfm_tables_task_lists_and_heading_anchors() {
+    let html = render(
+        "## Status Table\n\n| Step | Done |\n| :--- | ---- |\n| Build | yes |\n\n- [x] shipped\n- [ ] verified\n",
+    );
+
+    assert!(html.contains("<h2 id=\"status-table\">Status Table</h2>"));
+    assert!(html.contains("<table>"));
+    assert!(html.contains("<th style=\"text-align: left\">Step</th>"));
+    assert!(html.contains("type=\"checkbox\""));
+    assert!(html.contains("checked=\"\""));
+}
+
+#[test]
+fn rewrites_relative_doc_links_to_doc_ids() {
+    let html = render(
+        "See [beliefs](./core-beliefs.md#principles), [missing](./missing.md) and [web](https://example.com).\n",
+    );
+
+    assert!(html.contains("href=\"coda-doc:design-docs/core-beliefs.md#principles\""));
+    assert!(html.contains("href=\"./missing.md\""));
+    assert!(html.contains("href=\"https://example.com\""));
+}
+
+#[test]
+fn namespaces_doc_links_in_secondary_docs_roots() {
+    let html = render_in_namespace("See [beliefs](./core-beliefs.md).\n", Some("adr"));
+
+    assert!(html.contains("href=\"coda-doc:adr:design-docs/core-beliefs.md\""));
+}
+
+#[test]
+fn rewrites_links_into_other_docs_roots() {
+    let workspace = create_temp_workspace("doc-render");
+    write_doc_file(
+        &workspace.join("docs/design-docs/core-beliefs.md"),
+        "---\ntitle: Core Beliefs\ndate: 2026-02-14\n---\n\n## Principles\n",
+    );
+    write_doc_file(
+        &workspace.join("adr/0001-storage.md"),
+        "---\ntitle: Storage\ndate: 2026-02-14\n---\n\nSee [beliefs](../docs/design-docs/core-beliefs.md#principles).\n",
+    );
+    let docs_roots = [
+        DocsRoot {
+            namespace: None,
+            path: workspace.join("docs"),
+        },
+        DocsRoot {
+            namespace: Some("adr".to_string()),
+            path: workspace.join("adr"),
+        },
+    ];
+
+    let rendered = render_doc_html_from_root(&workspace, &docs_roots, "adr:0001-storage.md")
+        .expect("document should render");
+    teardown_workspace(&workspace);
+
+    assert_eq!(rendered.doc_id, "adr:0001-storage.md");
+    assert!(rendered
+        .html
+        .contains("href=\"coda-doc:design-docs/core-beliefs.md#principles\""));
+}
+
+#[test]
+fn keeps_code_language_classes_and_strips_unsafe_markup() {
+    let html = render(
+        "```rust\nfn main() {}\n```\n\n<script>alert(1)</script>\n\n<a href=\"javascript:alert(1)\" onclick=\"x()\">bad</a>\n",
+    );
+
+    assert!(html.contains("<code class=\"language-rust\">"));
+    assert!(!html.contains("<script"));
+    assert!(!html.contains("javascript:"));
+    assert!(!html.contains("onclick"));
+}
+
+#[test]
+fn drops_table_cell_styles_other_than_text_alignment() {
+    let html = render(
+        "<table><tr><td style=\"text-align: center;\">kept</td><td style=\"text-align: left; background: url(https://example.com/x.png)\">mixed</td><td style=\"text-align:justify\">odd</td><th style=\"color: red\">color</th></tr></table>\n",
+    );
+
+    assert!(html.contains("<td style=\"text-align: center;\">kept</td>"));
+    assert!(html.contains("<td>mixed</td>"));
+    assert!(html.contains("<td>odd</td>"));
+    assert!(html.contains("<th>color</th>"));
+    assert!(!html.contains("background"));
+}
@@ -0,0 +1,238 @@
+use crate::doc_ignore::DocIgnoreRules;
+use crate::plan_viewer::is_doc_file;
+use std::collections::HashSet;
+use std::fs;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::thread;
+
+const MAX_SCAN_WORKERS: usize = 8;
+pub const DOC_SCAN_CANCELLED_ERROR: &str = "doc scan cancelled: active project changed";
+
+pub type DocScanCancellation<'a> = &'a (dyn Fn() -> bool + Sync);
+type PendingDir = (PathBuf, Arc<DocIgnoreRules>);
+
+#[derive(Default)]
+struct WalkQueue {
+    pending_dirs: Vec<PendingDir>,
+    active_workers: usize,
+    visited_dirs: HashSet<PathBuf>,
+    doc_paths: Vec<PathBuf>,
+    error: Option<String>,
+}
+
+pub fn never_cancelled() -> bool {
+    false
+}
+
+pub fn scan_worker_count() -> usize {
+    thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(MAX_SCAN_WORKERS)
+}
+
+pub fn collect_doc_paths(
+    root: &Path,
+    ignore_rules: &DocIgnoreRules,
+    is_cancelled: DocScanCancellation<'_>,
+) -> Result<Vec<PathBuf>, String> {
+    let queue = Mutex::new(WalkQueue {
+        pending_dirs: vec![(root.to_path_buf(), Arc::new(ignore_rules.clone()))],
+        ..WalkQueue::default()
+    });
+    let queue_changed = Condvar::new();
+
+    thread::scope(|scope| {
+        for _ in 0..scan_worker_count() {
+            scope.spawn(|| walk_worker(&queue, &queue_changed, is_cancelled));
+        }
+    });
+
+    let queue = queue
+        .into_inner()
+        .map_err(|_| "doc scan queue lock poisoned".to_string())?;
+    if let Some(error) = queue.error {
+        return Err(error);
+    }
+    if is_cancelled() {
+        return Err(DOC_SCAN_CANCELLED_ERROR.to_string());
+    }
+
+    let mut doc_paths = queue.doc_paths;
+    doc_paths.sort();
+    Ok(doc_paths)
+}
+
+pub fn parallel_map<T, R>(
+    items: &[T],
+    is_cancelled: DocScanCancellation<'_>,
+    map_item: impl Fn(&T) -> Result<R, String> + Sync,
+) -> Result<Vec<R>, String>
+where
+    T: Sync,
+    R: Send,
+{
+    let next_index = AtomicUsize::new(0);
+    let failed = AtomicBool::new(false);
+    let worker_count = scan_worker_count().min(items.len());
+
+    let worker_results = thread::scope(|scope| {
+        let workers = (0..worker_count)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut mapped = Vec::new();
+                    loop {
+                        if is_cancelled() {
+                            return Err(DOC_SCAN_CANCELLED_ERROR.to_string());
+                        }
+
+                        let index = next_index.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            return Ok(mapped);
+                        };
+                        if failed.load(Ordering::Relaxed) {
+                            return Ok(mapped);
+                        }
+
+                        match map_item(item) {
+                            Ok(result) => mapped.push((index, result)),
+                            Err(error) => {
+                                failed.store(true, Ordering::Relaxed);
+                                return Err(error);
+                            }
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|_| Err("doc scan worker panicked".to_string()))
+            })
+            .collect::<Vec<Result<Vec<(usize, R)>, String>>>()
+    });
+
+    let mut indexed_results = Vec::with_capacity(items.len());
+    for worker_result in worker_results {
+        indexed_results.extend(worker_result?);
+    }
+    indexed_results.sort_by_key(|(index, _)| *index);
+
+    Ok(indexed_results
+        .into_iter()
+        .map(|(_, mapped)| mapped)
+        .collect())
+}
+
+fn walk_worker(
+    queue: &Mutex<WalkQueue>,
+    queue_changed: &Condvar,
+    is_cancelled: DocScanCancellation<'_>,
+) {
+    while let Some((dir, ignore_rules)) = next_walk_dir(queue, queue_changed, is_cancelled) {
+        let walked = walk_dir(&dir, &ignore_rules);
+
+        let mut state = queue.lock().unwrap_or_else(PoisonError::into_inner);
+        match walked {
+            Ok((sub_dirs, doc_paths)) => {
+                state.pending_dirs.extend(sub_dirs);
+                state.doc_paths.extend(doc_paths);
+            }
+            Err(error) => {
+                state.error.get_or_insert(error);
+            }
+        }
+        state.active_workers -= 1;
+        queue_changed.notify_all();
+    }
+}
+
+fn next_walk_dir(
+    queue: &Mutex<WalkQueue>,
+    queue_changed: &Condvar,
+    is_cancelled: DocScanCancellation<'_>,
+) -> Option<PendingDir> {
+    let mut state = queue.lock().unwrap_or_else(PoisonError::into_inner);
+
+    loop {
+        if state.error.is_some() || is_cancelled() {
+            queue_changed.notify_all();
+            return None;
+        }
+
+        if let Some((dir, ignore_rules)) = state.pending_dirs.pop() {
+            let dir_canonical = match dir.canonicalize() {
+                Ok(dir_canonical) => dir_canonical,
+                Err(error) => {
+                    log::warn!("skipping directory {}: {error}", dir.display());
+                    continue;
+                }
+            };
+            if !state.visited_dirs.insert(dir_canonical) {
+                log::warn!(
+                    "skipping directory {} already visited through a symlink",
+                    dir.display()
+                );
+                continue;
+            }
+
+            state.active_workers += 1;
+            return Some((dir, ignore_rules));
+        }
+
+        if state.active_workers == 0 {
+            queue_changed.notify_all();
+            return None;
+        }
+
+        state = queue_changed
+            .wait(state)
+            .unwrap_or_else(PoisonError::into_inner);
+    }
+}
+
+fn walk_dir(
+    dir: &Path,
+    ignore_rules: &Arc<DocIgnoreRules>,
+) -> Result<(Vec<PendingDir>, Vec<PathBuf>), String> {
+    let ignore_rules = ignore_rules
+        .with_dir_ignore_files(dir)
+        .map_or_else(|| Arc::clone(ignore_rules), Arc::new);
+    let entries = fs::read_dir(dir)
+        .map_err(|error| format!("failed to read directory {}: {error}", dir.display()))?;
+    let mut sub_dirs = Vec::new();
+    let mut doc_paths = Vec::new();
+
+    for entry in entries {
+        let entry = entry.map_err(|error| {
+            format!(
+                "failed to read directory entry in {}: {error}",
+                dir.display()
+            )
+        })?;
+        let entry_path = entry.path();
+        let is_dir = entry_path.is_dir();
+
+        if ignore_rules.matches(&entry_path, is_dir) {
+            continue;
+        }
+
+        if is_dir {
+            sub_dirs.push((entry_path, Arc::clone(&ignore_rules)));
+        } else if is_doc_file(&entry_path) {
+            doc_paths.push(entry_path);
+        }
+    }
+
+    Ok((sub_dirs, doc_paths))
+}
+
+#[cfg(test)]
+#[path = "doc_scan_tests.rs"]
+mod tests;
@@ -0,0 +1,44 @@
+use super::{collect_doc_paths, never_cancelled, parallel_map, DOC_SCAN_CANCELLED_ERROR};
+use crate::doc_ignore::DocIgnoreRules;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn keeps_input_order_when_mapping_in_parallel() {
+    let items = (0..500).collect::<Vec<usize>>();
+    let mapped = parallel_map(&items, &never_cancelled, |item| Ok(item * 2))
+        .expect("mapping should succeed");
+
+    assert_eq!(
+        mapped,
+        (0..500).map(|item| item * 2).collect::<Vec<usize>>()
+    );
+}
+
+#[test]
+fn stops_mapping_once_cancelled() {
+    let items = (0..10_000).collect::<Vec<usize>>();
+    let mapped_count = AtomicUsize::new(0);
+    let is_cancelled = || mapped_count.load(Ordering::Relaxed) >= 10;
+
+    let error = parallel_map(&items, &is_cancelled, |item| {
+        mapped_count.fetch_add(1, Ordering::Relaxed);
+        Ok(*item)
+    })
+    .expect_err("cancelled mapping should fail");
+
+    assert_eq!(error, DOC_SCAN_CANCELLED_ERROR);
+    assert!(mapped_count.load(Ordering::Relaxed) < items.len());
+}
+
+#[test]
+fn skips_directories_that_cannot_be_resolved() {
+    let missing_root = std::env::temp_dir().join(format!(
+        "coda-doc-scan-tests-missing-{}",
+        std::process::id()
+    ));
+
+    let doc_paths = collect_doc_paths(&missing_root, &DocIgnoreRules::default(), &never_cancelled)
+        .expect("unresolvable directories should be skipped");
+
+    assert!(doc_paths.is_empty());
+}
@@ -0,0 +1,164 @@
+use crate::plan_viewer::DocSummary;
+use std::collections::{HashMap, HashSet};
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+#[derive(Debug, Clone)]
+struct CachedDocSummary {
+    modified_at: Option<SystemTime>,
+    size: u64,
+    summary: DocSummary,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DocSummaryScanStats {
+    pub cached: usize,
+    pub parsed: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DocSummaryCache {
+    entries: HashMap<PathBuf, CachedDocSummary>,
+    current_scan: DocSummaryScanStats,
+    last_scan: DocSummaryScanStats,
+}
+
+#[derive(Debug, Default)]
+struct DocsRootCacheSlot {
+    cache: DocSummaryCache,
+    generation: u64,
+    invalidated_at: HashMap<PathBuf, u64>,
+    active_scans: usize,
+}
+
+#[derive(Clone, Default)]
+pub struct DocSummaryCacheState {
+    inner: Arc<Mutex<HashMap<String, HashMap<PathBuf, DocsRootCacheSlot>>>>,
+}
+
+impl DocSummaryCache {
+    pub fn get(&mut self, doc_path: &Path, metadata: &Metadata) -> Option<DocSummary> {
+        let entry = self.entries.get(doc_path)?;
+        if entry.modified_at != metadata.modified().ok() || entry.size != metadata.len() {
+            return None;
+        }
+
+        self.current_scan.cached += 1;
+        Some(entry.summary.clone())
+    }
+
+    pub fn insert(&mut self, doc_path: PathBuf, metadata: &Metadata, summary: DocSummary) {
+        self.current_scan.parsed += 1;
+        self.entries.insert(
+            doc_path,
+            CachedDocSummary {
+                modified_at: metadata.modified().ok(),
+                size: metadata.len(),
+                summary,
+            },
+        );
+    }
+
+    pub fn finish_scan(&mut self, scanned_paths: &[PathBuf]) -> DocSummaryScanStats {
+        let scanned_paths = scanned_paths.iter().collect::<HashSet<&PathBuf>>();
+        self.entries
+            .retain(|doc_path, _| scanned_paths.contains(doc_path));
+        self.last_scan = std::mem::take(&mut self.current_scan);
+        self.last_scan
+    }
+
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn last_scan(&self) -> DocSummaryScanStats {
+        self.last_scan
+    }
+
+    pub fn invalidate(&mut self, doc_path: &Path) {
+        self.entries.remove(doc_path);
+    }
+}
+
+impl DocSummaryCacheState {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn with_docs_root_cache<T>(
+        &self,
+        project_id: &str,
+        docs_root: &Path,
+        scan: impl FnOnce(&mut DocSummaryCache) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let (mut cache, scan_generation) = self.with_slot(project_id, docs_root, |slot| {
+            slot.active_scans += 1;
+            (slot.cache.clone(), slot.generation)
+        })?;
+
+        let result = scan(&mut cache);
+
+        self.with_slot(project_id, docs_root, |slot| {
+            slot.active_scans -= 1;
+            if result.is_ok() {
+                for (doc_path, invalidated_at) in &slot.invalidated_at {
+                    if *invalidated_at > scan_generation {
+                        cache.invalidate(doc_path);
+                    }
+                }
+                slot.cache = cache;
+            }
+            if slot.active_scans == 0 {
+                slot.invalidated_at.clear();
+            }
+        })?;
+
+        result
+    }
+
+    pub fn invalidate_doc_paths(&self, project_id: &str, doc_paths: &[PathBuf]) {
+        let Ok(mut inner) = self.inner.lock() else {
+            return;
+        };
+        let Some(project_caches) = inner.get_mut(project_id) else {
+            return;
+        };
+
+        for doc_path in doc_paths {
+            for (docs_root, slot) in project_caches.iter_mut() {
+                if doc_path.starts_with(docs_root) {
+                    slot.cache.invalidate(doc_path);
+                    if slot.active_scans > 0 {
+                        slot.generation += 1;
+                        slot.invalidated_at
+                            .insert(doc_path.clone(), slot.generation);
+                    }
+                }
+            }
+        }
+    }
+
+    fn with_slot<T>(
+        &self,
+        project_id: &str,
+        docs_root: &Path,
+        update: impl FnOnce(&mut DocsRootCacheSlot) -> T,
+    ) -> Result<T, String> {
+        let mut inner = self
+            .inner
+            .lock()
+            .map_err(|_| "doc summary cache lock poisoned".to_string())?;
+        let slot = inner
+            .entry(project_id.to_string())
+            .or_default()
+            .entry(docs_root.to_path_buf())
+            .or_default();
+
+        Ok(update(slot))
+    }
+}
+
+#[cfg(test)]
+#[path = "doc_summary_cache_tests.rs"]
+mod tests;
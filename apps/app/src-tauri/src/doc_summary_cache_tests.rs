@@ -0,0 +1,229 @@
+use super::{DocSummaryCacheState, DocSummaryScanStats};
+use crate::doc_scan::never_cancelled;
+use crate::plan_viewer::list_doc_summaries_from_root;
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+const BENCHMARK_DOC_COUNT: usize = 3000;
+
+fn synthetic_doc(index: usize) -> String {
+    format!(
+        "---\ntitle: Synthetic {index}\ndate: 2026-02-{day:02}\nstatus: active\ntags: [synthetic, doc-{index}]\n---\n\n## Notes\n\n- [x] first\n- [ ] second\n",
+        day = index % 28 + 1
+    )
+}
+
+fn list_cached(
+    cache_state: &DocSummaryCacheState,
+    workspace: &Path,
+    docs_root: &Path,
+) -> (usize, DocSummaryScanStats) {
+    cache_state
+        .with_docs_root_cache("alpha", docs_root, |cache| {
+            let summaries = list_doc_summaries_from_root(
+                workspace,
+                docs_root,
+                &[],
+                true,
+                cache,
+                &never_cancelled,
+            )?;
+            Ok((summaries.len(), cache.last_scan()))
+        })
+        .expect("cached listing should work")
+}
+
+#[test]
+fn reparses_only_changed_and_invalidated_docs() {
+    let workspace = create_temp_workspace("doc-summary-cache");
+    let docs_root = workspace.join("docs");
+    let cache_state = DocSummaryCacheState::new();
+
+    write_doc_file(&docs_root.join("PRD.md"), &synthetic_doc(1));
+    write_doc_file(&docs_root.join("plans/plan.md"), &synthetic_doc(2));
+
+    assert_eq!(
+        list_cached(&cache_state, &workspace, &docs_root),
+        (
+            2,
+            DocSummaryScanStats {
+                cached: 0,
+                parsed: 2
+            }
+        )
+    );
+    assert_eq!(
+        list_cached(&cache_state, &workspace, &docs_root),
+        (
+            2,
+            DocSummaryScanStats {
+                cached: 2,
+                parsed: 0
+            }
+        )
+    );
+
+    write_doc_file(
+        &docs_root.join("PRD.md"),
+        &format!("{}\nMore detail.\n", synthetic_doc(1)),
+    );
+    write_doc_file(&docs_root.join("solutions/fix.md"), &synthetic_doc(3));
+    assert_eq!(
+        list_cached(&cache_state, &workspace, &docs_root),
+        (
+            3,
+            DocSummaryScanStats {
+                cached: 1,
+                parsed: 2
+            }
+        )
+    );
+
+    cache_state.invalidate_doc_paths("alpha", &[docs_root.join("plans/plan.md")]);
+    fs::remove_file(docs_root.join("solutions/fix.md")).expect("doc should be removed");
+    assert_eq!(
+        list_cached(&cache_state, &workspace, &docs_root),
+        (
+            2,
+            DocSummaryScanStats {
+                cached: 1,
+                parsed: 1
+            }
+        )
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn scans_without_holding_the_cache_lock_and_keeps_concurrent_invalidations() {
+    let workspace = create_temp_workspace("doc-summary-cache");
+    let docs_root = workspace.join("docs");
+    let doc_path = docs_root.join("PRD.md");
+    let cache_state = DocSummaryCacheState::new();
+    write_doc_file(&doc_path, &synthetic_doc(1));
+    list_cached(&cache_state, &workspace, &docs_root);
+
+    let (count, stats) = cache_state
+        .with_docs_root_cache("alpha", &docs_root, |cache| {
+            let summaries = list_doc_summaries_from_root(
+                &workspace,
+                &docs_root,
+                &[],
+                true,
+                cache,
+                &never_cancelled,
+            )?;
+            cache_state.invalidate_doc_paths("alpha", std::slice::from_ref(&doc_path));
+            Ok((summaries.len(), cache.last_scan()))
+        })
+        .expect("cached listing should work");
+    assert_eq!(
+        (count, stats),
+        (
+            1,
+            DocSummaryScanStats {
+                cached: 1,
+                parsed: 0
+            }
+        )
+    );
+
+    assert_eq!(
+        list_cached(&cache_state, &workspace, &docs_root),
+        (
+            1,
+            DocSummaryScanStats {
+                cached: 0,
+                parsed: 1
+            }
+        )
+    );
+
+    teardown_workspace(&workspace);
+}
+
+fn assert_cold_then_warm_listing(doc_count: usize) {
+    let workspace = create_temp_workspace("doc-summary-cache");
+    let docs_root = workspace.join("docs");
+    let cache_state = DocSummaryCacheState::new();
+
+    for index in 0..doc_count {
+        write_doc_file(
+            &docs_root
+                .join(format!("section-{}", index % 20))
+                .join(format!("doc-{index}.md")),
+            &synthetic_doc(index),
+        );
+    }
+
+    assert_eq!(
+        list_cached(&cache_state, &workspace, &docs_root),
+        (
+            doc_count,
+            DocSummaryScanStats {
+                cached: 0,
+                parsed: doc_count
+            }
+        )
+    );
+    assert_eq!(
+        list_cached(&cache_state, &workspace, &docs_root),
+        (
+            doc_count,
+            DocSummaryScanStats {
+                cached: doc_count,
+                parsed: 0
+            }
+        )
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn serves_warm_listing_entirely_from_cache() {
+    assert_cold_then_warm_listing(5);
+}
+
+#[test]
+fn benchmarks_cold_and_warm_listing_of_thousands_of_docs() {
+    let workspace = create_temp_workspace("doc-summary-cache");
+    let docs_root = workspace.join("docs");
+    let cache_state = DocSummaryCacheState::new();
+
+    for index in 0..BENCHMARK_DOC_COUNT {
+        write_doc_file(
+            &docs_root
+                .join(format!("section-{}", index % 20))
+                .join(format!("doc-{index}.md")),
+            &synthetic_doc(index),
+        );
+    }
+
+    let cold_started_at = Instant::now();
+    let (cold_count, cold_stats) = list_cached(&cache_state, &workspace, &docs_root);
+    let cold_elapsed = cold_started_at.elapsed();
+
+    let warm_started_at = Instant::now();
+    let (warm_count, warm_stats) = list_cached(&cache_state, &workspace, &docs_root);
+    let warm_elapsed = warm_started_at.elapsed();
+
+    println!(
+        "doc summary cache benchmark: {BENCHMARK_DOC_COUNT} docs, cold {cold_elapsed:?}, warm {warm_elapsed:?}"
+    );
+    assert_eq!(cold_count, BENCHMARK_DOC_COUNT);
+    assert_eq!(warm_count, BENCHMARK_DOC_COUNT);
+    assert_eq!(cold_stats.parsed, BENCHMARK_DOC_COUNT);
+    assert_eq!(
+        warm_stats,
+        DocSummaryScanStats {
+            cached: BENCHMARK_DOC_COUNT,
+            parsed: 0
+        }
+    );
+
+    teardown_workspace(&workspace);
+}
@@ -0,0 +1,102 @@
+use crate::doc_editor::{ensure_doc_revision, read_existing_doc};
+use crate::plan_viewer::{get_doc_document_from_root, write_doc_file_atomic, DocDocument};
+use crate::project_runtime::ProjectRegistryState;
+use std::path::Path;
+use tauri::State;
+
+const TASK_BULLET_MARKERS: [char; 3] = ['-', '*', '+'];
+
+#[tauri::command]
+pub fn toggle_doc_task(
+    doc_id: String,
+    base_revision: String,
+    line: usize,
+    checked: bool,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<DocDocument, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&doc_id)?;
+    toggle_doc_task_from_root(
+        &active_project.root_path,
+        &docs_root.path,
+        &doc_id,
+        &base_revision,
+        line,
+        checked,
+    )
+    .map(|document| document.scoped(docs_root))
+}
+
+pub fn toggle_doc_task_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    base_revision: &str,
+    line: usize,
+    checked: bool,
+) -> Result<DocDocument, String> {
+    let (doc_path, contents) = read_existing_doc(workspace_root, docs_root, doc_id)?;
+    ensure_doc_revision(doc_id, &contents, base_revision)?;
+
+    let document = get_doc_document_from_root(workspace_root, docs_root, doc_id)?;
+    if !document.tasks.iter().any(|task| task.line == line) {
+        return Err(format!(
+            "task toggle failed: line {line} in {doc_id} is not a task list item"
+        ));
+    }
+
+    let mut lines = contents
+        .split('\n')
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+    let target_line = lines
+        .get_mut(line.saturating_sub(1))
+        .ok_or_else(|| format!("task toggle failed: line {line} is out of range for {doc_id}"))?;
+    *target_line = set_task_marker(target_line, checked).ok_or_else(|| {
+        format!("task toggle failed: line {line} in {doc_id} has no task checkbox")
+    })?;
+
+    write_doc_file_atomic(&doc_path, &lines.join("\n"))?;
+    get_doc_document_from_root(workspace_root, docs_root, doc_id)
+}
+
+fn set_task_marker(line: &str, checked: bool) -> Option<String> {
+    let indent_length = line.len() - line.trim_start().len();
+    let item = &line[indent_length..];
+
+    let after_bullet = if item.starts_with(TASK_BULLET_MARKERS) {
+        &item[1..]
+    } else {
+        let digits_length = item
+            .chars()
+            .take_while(|character| character.is_ascii_digit())
+            .count();
+        if digits_length == 0 {
+            return None;
+        }
+        item[digits_length..].strip_prefix(['.', ')'])?
+    };
+
+    let spacing_length = after_bullet.len() - after_bullet.trim_start().len();
+    if spacing_length == 0 {
+        return None;
+    }
+
+    let marker_start = line.len() - after_bullet.len() + spacing_length;
+    let marker = line.get(marker_start..(marker_start + 3))?;
+    if !matches!(marker, "[ ]" | "[x]" | "[X]") {
+        return None;
+    }
+
+    let next_marker = if checked { "[x]" } else { "[ ]" };
+    Some(format!(
+        "{}{}{}",
+        &line[..marker_start],
+        next_marker,
+        &line[(marker_start + 3)..]
+    ))
+}
+
+#[cfg(test)]
+#[path = "doc_tasks_tests.rs"]
+mod tests;
@@ -0,0 +1,95 @@
+use super::{set_task_marker, toggle_doc_task_from_root};
+use crate::plan_viewer::content_revision;
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
+use std::fs;
+
+const PLAN_CONTENTS: &str = "---\ntitle: Progress Plan\ndate: 2026-02-19\nstatus: active\n---\n\n## Approach\n\n1. **Build**\n   - [x] Action: implement\n   - [ ] Exit criteria: tests pass\n";
+
+#[test]
+fn counts_plan_tasks_with_file_line_numbers() {
+    let workspace = create_temp_workspace("doc-tasks");
+    let docs_root = workspace.join("docs");
+    write_doc_file(
+        &docs_root.join("plans/active/2026-02-19-progress-plan.md"),
+        PLAN_CONTENTS,
+    );
+
+    let document = crate::plan_viewer::get_doc_document_from_root(
+        &workspace,
+        &docs_root,
+        "plans/active/2026-02-19-progress-plan.md",
+    )
+    .expect("plan should load");
+
+    assert_eq!(document.tasks_total, 2);
+    assert_eq!(document.tasks_done, 1);
+    assert_eq!(document.tasks[0].line, 10);
+    assert_eq!(document.tasks[1].line, 11);
+    assert_eq!(document.tasks[1].text, "Exit criteria: tests pass");
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn toggles_task_checkbox_and_writes_file_back() {
+    let workspace = create_temp_workspace("doc-tasks");
+    let docs_root = workspace.join("docs");
+    let plan_path = docs_root.join("plans/active/2026-02-19-progress-plan.md");
+    write_doc_file(&plan_path, PLAN_CONTENTS);
+
+    let document = toggle_doc_task_from_root(
+        &workspace,
+        &docs_root,
+        "plans/active/2026-02-19-progress-plan.md",
+        &content_revision(PLAN_CONTENTS),
+        11,
+        true,
+    )
+    .expect("task should toggle");
+
+    assert_eq!(document.tasks_done, 2);
+    assert_eq!(
+        fs::read_to_string(&plan_path).expect("plan should be readable"),
+        PLAN_CONTENTS.replace("- [ ] Exit", "- [x] Exit")
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn rejects_toggle_for_lines_that_are_not_tasks() {
+    let workspace = create_temp_workspace("doc-tasks");
+    let docs_root = workspace.join("docs");
+    write_doc_file(
+        &docs_root.join("plans/active/2026-02-19-progress-plan.md"),
+        PLAN_CONTENTS,
+    );
+
+    let error = toggle_doc_task_from_root(
+        &workspace,
+        &docs_root,
+        "plans/active/2026-02-19-progress-plan.md",
+        &content_revision(PLAN_CONTENTS),
+        9,
+        true,
+    )
+    .expect_err("non-task line should be rejected");
+
+    assert!(error.contains("is not a task list item"));
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn rewrites_only_the_checkbox_marker() {
+    assert_eq!(
+        set_task_marker("  * [X] done\r", false).as_deref(),
+        Some("  * [ ] done\r")
+    );
+    assert_eq!(
+        set_task_marker("12. [ ] ordered", true).as_deref(),
+        Some("12. [x] ordered")
+    );
+    assert_eq!(set_task_marker("- plain item", true), None);
+    assert_eq!(set_task_marker("-[ ] no space", true), None);
+}
@@ -0,0 +1,244 @@
+use crate::doc_editor::{create_doc_from_root, read_existing_doc};
+use crate::doc_gardener::format_date;
+use crate::plan_viewer::{is_template_file, split_frontmatter, DocDocument};
+use crate::project_runtime::ProjectRegistryState;
+use serde::Deserialize;
+use serde_yaml::Value;
+use std::path::{Path, PathBuf};
+use tauri::State;
+use time::{Date, OffsetDateTime};
+
+const DEFAULT_PLAN_KIND: &str = "feat";
+const TEMPLATE_FILE_NAME: &str = ".template.md";
+const TITLE_PLACEHOLDER: &str = "{{title}}";
+const DATE_PLACEHOLDER: &str = "{{date}}";
+const SLUG_PLACEHOLDER: &str = "{{slug}}";
+const BUILTIN_PLAN_TEMPLATE: &str = "---\ntitle:\ndate:\nstatus: draft\ntags: []\nmilestone:\n---\n\n## Goal\n\n## Context\n\n## Approach\n\n1. **Step name**\n   - [ ] Action:\n   - [ ] Deliverables:\n   - [ ] Exit criteria:\n\n## Validation\n\n## Progress Log\n";
+const BUILTIN_SOLUTION_TEMPLATE: &str = "---\ntitle:\ndate:\ntags: []\nstatus: active\n---\n\n## Problem\n\n## Root Cause\n\n## Solution\n\n## Prevention\n\n## Related\n";
+const BUILTIN_BRAINSTORM_TEMPLATE: &str = "---\ntitle:\ndate:\ntags: []\nstatus: draft\n---\n\n## Problem\n\n## Ideas\n\n## Open Questions\n\n## Next Steps\n";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DocTemplateTarget {
+    Plan,
+    Solution,
+    Brainstorm,
+}
+
+impl DocTemplateTarget {
+    fn section_dir(self) -> &'static str {
+        match self {
+            Self::Plan => "plans/active",
+            Self::Solution => "solutions",
+            Self::Brainstorm => "brainstorms",
+        }
+    }
+
+    fn default_template_id(self) -> String {
+        let template_dir = match self {
+            Self::Plan => "plans",
+            Self::Solution => "solutions",
+            Self::Brainstorm => "brainstorms",
+        };
+
+        format!("{template_dir}/{TEMPLATE_FILE_NAME}")
+    }
+
+    fn builtin_template(self) -> &'static str {
+        match self {
+            Self::Plan => BUILTIN_PLAN_TEMPLATE,
+            Self::Solution => BUILTIN_SOLUTION_TEMPLATE,
+            Self::Brainstorm => BUILTIN_BRAINSTORM_TEMPLATE,
+        }
+    }
+
+    fn file_name(self, date: &str, slug: &str, plan_kind: &str) -> String {
+        match self {
+            Self::Plan => format!("{date}-{plan_kind}-{slug}-plan.md"),
+            Self::Solution | Self::Brainstorm => format!("{date}-{slug}.md"),
+        }
+    }
+}
+
+#[tauri::command]
+pub fn create_doc_from_template(
+    target: DocTemplateTarget,
+    title: String,
+    template_id: Option<String>,
+    plan_kind: Option<String>,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<DocDocument, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, template_id) = match template_id {
+        Some(template_id) => {
+            let (docs_root, template_id) = active_project.resolve_docs_root(&template_id)?;
+            (docs_root, Some(template_id))
+        }
+        None => (active_project.resolve_docs_root("")?.0, None),
+    };
+
+    create_doc_from_template_from_root(
+        &active_project.root_path,
+        &docs_root.path,
+        target,
+        &title,
+        template_id.as_deref(),
+        plan_kind.as_deref(),
+        OffsetDateTime::now_utc().date(),
+    )
+    .map(|document| document.scoped(docs_root))
+}
+
+pub fn create_doc_from_template_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    target: DocTemplateTarget,
+    title: &str,
+    template_id: Option<&str>,
+    plan_kind: Option<&str>,
+    today: Date,
+) -> Result<DocDocument, String> {
+    let title = title.trim();
+    let slug = slugify_title(title);
+    if slug.is_empty() {
+        return Err(
+            "template instantiation failed: title must contain letters or digits".to_string(),
+        );
+    }
+
+    let plan_kind = slugify_title(plan_kind.unwrap_or(DEFAULT_PLAN_KIND));
+    if plan_kind.is_empty() {
+        return Err("template instantiation failed: plan kind must not be empty".to_string());
+    }
+
+    let (template_path, template_contents) = match template_id {
+        Some(template_id) => read_template(workspace_root, docs_root, template_id)?,
+        None => read_default_template(workspace_root, docs_root, target)?,
+    };
+    let (template_syntax, template_frontmatter, template_body) =
+        split_frontmatter(&template_contents, &template_path)?;
+
+    let date = format_date(today);
+    let placeholders = [
+        (TITLE_PLACEHOLDER, title),
+        (DATE_PLACEHOLDER, date.as_str()),
+        (SLUG_PLACEHOLDER, slug.as_str()),
+    ];
+
+    let mut frontmatter = template_syntax
+        .parse_mapping(&template_frontmatter, &template_path)
+        .map_err(|error| format!("template instantiation failed: {error}"))?;
+    for value in frontmatter.values_mut() {
+        substitute_value_placeholders(value, &placeholders);
+    }
+    frontmatter.insert(Value::from("title"), Value::from(title));
+    frontmatter.insert(Value::from("date"), Value::from(date.as_str()));
+
+    let doc_id = format!(
+        "{}/{}",
+        target.section_dir(),
+        target.file_name(&date, &slug, &plan_kind)
+    );
+
+    create_doc_from_root(
+        workspace_root,
+        docs_root,
+        &doc_id,
+        frontmatter,
+        &substitute_placeholders(&template_body, &placeholders),
+    )
+}
+
+fn read_template(
+    workspace_root: &Path,
+    docs_root: &Path,
+    template_id: &str,
+) -> Result<(PathBuf, String), String> {
+    let is_template = Path::new(template_id)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(is_template_file);
+    if !is_template {
+        return Err(format!(
+            "template instantiation failed: {template_id} is not a {TEMPLATE_FILE_NAME} file"
+        ));
+    }
+
+    read_existing_doc(workspace_root, docs_root, template_id)
+}
+
+fn read_default_template(
+    workspace_root: &Path,
+    docs_root: &Path,
+    target: DocTemplateTarget,
+) -> Result<(PathBuf, String), String> {
+    let template_id = target.default_template_id();
+    let template_path = docs_root.join(&template_id);
+    if template_path.is_file() {
+        return read_template(workspace_root, docs_root, &template_id);
+    }
+
+    Ok((template_path, target.builtin_template().to_string()))
+}
+
+fn substitute_value_placeholders(value: &mut Value, placeholders: &[(&str, &str)]) {
+    match value {
+        Value::String(text) => *text = substitute_placeholders(text, placeholders),
+        Value::Sequence(items) => {
+            for item in items {
+                substitute_value_placeholders(item, placeholders);
+            }
+        }
+        Value::Mapping(fields) => {
+            for field in fields.values_mut() {
+                substitute_value_placeholders(field, placeholders);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn substitute_placeholders(text: &str, placeholders: &[(&str, &str)]) -> String {
+    let mut substituted = String::with_capacity(text.len());
+    let mut remaining = text;
+
+    while let Some(start) = remaining.find("{{") {
+        substituted.push_str(&remaining[..start]);
+        remaining = &remaining[start..];
+
+        let placeholder = placeholders
+            .iter()
+            .find(|(placeholder, _)| remaining.starts_with(placeholder));
+        match placeholder {
+            Some((placeholder, replacement)) => {
+                substituted.push_str(replacement);
+                remaining = &remaining[placeholder.len()..];
+            }
+            None => {
+                substituted.push_str("{{");
+                remaining = &remaining[2..];
+            }
+        }
+    }
+
+    substituted.push_str(remaining);
+    substituted
+}
+
+fn slugify_title(title: &str) -> String {
+    let mut slug = String::new();
+
+    for character in title.chars().flat_map(char::to_lowercase) {
+        if character.is_ascii_alphanumeric() {
+            slug.push(character);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    slug.trim_end_matches('-').to_string()
+}
+
+#[cfg(test)]
+#[path = "doc_templates_tests.rs"]
+mod tests;
@@ -0,0 +1,192 @@
+use super::{create_doc_from_template_from_root, slugify_title, DocTemplateTarget};
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
+use std::fs;
+use time::{Date, Month};
+
+fn test_date() -> Date {
+    Date::from_calendar_date(2026, Month::February, 19).expect("test date should be valid")
+}
+
+#[test]
+fn instantiates_plan_template_into_dated_active_plan() {
+    let workspace = create_temp_workspace("doc-templates");
+    let docs_root = workspace.join("docs");
+    write_doc_file(
+        &docs_root.join("plans/.template.md"),
+        "---\ntitle:\ndate:\nstatus: draft\ntags: []\nmilestone:\n---\n\n## Goal\n\n## Approach\n\n1. **Step name**\n   - [ ] Action:\n",
+    );
+
+    let document = create_doc_from_template_from_root(
+        &workspace,
+        &docs_root,
+        DocTemplateTarget::Plan,
+        "Sidebar Search: v2",
+        None,
+        None,
+        test_date(),
+    )
+    .expect("plan template should instantiate");
+
+    assert_eq!(
+        document.id,
+        "plans/active/2026-02-19-feat-sidebar-search-v2-plan.md"
+    );
+    assert_eq!(document.title.as_deref(), Some("Sidebar Search: v2"));
+    assert_eq!(document.date.as_deref(), Some("2026-02-19"));
+    assert_eq!(document.status.as_deref(), Some("draft"));
+    assert_eq!(document.tasks_total, 1);
+    assert!(!document.is_template);
+
+    let duplicate_error = create_doc_from_template_from_root(
+        &workspace,
+        &docs_root,
+        DocTemplateTarget::Plan,
+        "Sidebar Search: v2",
+        None,
+        None,
+        test_date(),
+    )
+    .expect_err("existing plan should not be overwritten");
+    assert!(duplicate_error.contains("already exists"));
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn substitutes_placeholders_in_frontmatter_and_body() {
+    let workspace = create_temp_workspace("doc-templates");
+    let docs_root = workspace.join("docs");
+    write_doc_file(
+        &docs_root.join("solutions/.template.md"),
+        "---\ntitle:\ndate:\ntags: [\"{{slug}}\"]\ncategory: fix\n---\n\n# {{title}}\n\nWritten {{date}} for `{{slug}}`.\n",
+    );
+
+    let document = create_doc_from_template_from_root(
+        &workspace,
+        &docs_root,
+        DocTemplateTarget::Solution,
+        "Watcher drops events",
+        None,
+        None,
+        test_date(),
+    )
+    .expect("solution template should instantiate");
+
+    assert_eq!(document.id, "solutions/2026-02-19-watcher-drops-events.md");
+    assert_eq!(document.tags, vec!["watcher-drops-events"]);
+    assert_eq!(
+        document.markdown_body,
+        "\n# Watcher drops events\n\nWritten 2026-02-19 for `watcher-drops-events`.\n"
+    );
+    assert!(fs::read_to_string(docs_root.join(&document.id))
+        .expect("instantiated doc should be readable")
+        .contains("category: fix"));
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn does_not_substitute_placeholders_inside_substituted_values() {
+    let workspace = create_temp_workspace("doc-templates");
+    let docs_root = workspace.join("docs");
+    write_doc_file(
+        &docs_root.join("solutions/.template.md"),
+        "---\ntitle:\n---\n\n# {{title}} ({{unknown}})\n\nWritten {{date}}.\n",
+    );
+
+    let document = create_doc_from_template_from_root(
+        &workspace,
+        &docs_root,
+        DocTemplateTarget::Solution,
+        "Use {{date}} tokens",
+        None,
+        None,
+        test_date(),
+    )
+    .expect("solution template should instantiate");
+
+    assert_eq!(document.title.as_deref(), Some("Use {{date}} tokens"));
+    assert_eq!(
+        document.markdown_body,
+        "\n# Use {{date}} tokens ({{unknown}})\n\nWritten 2026-02-19.\n"
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn rejects_missing_or_non_template_sources() {
+    let workspace = create_temp_workspace("doc-templates");
+    let docs_root = workspace.join("docs");
+    write_doc_file(
+        &docs_root.join("brainstorms/idea.md"),
+        "---\ntitle: Idea\n---\n",
+    );
+
+    let missing_error = create_doc_from_template_from_root(
+        &workspace,
+        &docs_root,
+        DocTemplateTarget::Brainstorm,
+        "New idea",
+        Some("brainstorms/archive/.template.md"),
+        None,
+        test_date(),
+    )
+    .expect_err("missing explicit brainstorm template should be rejected");
+    assert!(missing_error.contains("failed to locate document"));
+
+    let non_template_error = create_doc_from_template_from_root(
+        &workspace,
+        &docs_root,
+        DocTemplateTarget::Brainstorm,
+        "New idea",
+        Some("brainstorms/idea.md"),
+        None,
+        test_date(),
+    )
+    .expect_err("regular docs should not be used as templates");
+    assert!(non_template_error.contains("is not a .template.md file"));
+
+    assert_eq!(
+        slugify_title("  Fix: CRLF -- handling!  "),
+        "fix-crlf-handling"
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn falls_back_to_builtin_templates_when_directory_template_is_missing() {
+    let workspace = create_temp_workspace("doc-templates");
+    let docs_root = workspace.join("docs");
+
+    let solution = create_doc_from_template_from_root(
+        &workspace,
+        &docs_root,
+        DocTemplateTarget::Solution,
+        "Fix CRLF handling",
+        None,
+        None,
+        test_date(),
+    )
+    .expect("solution should use the built-in template");
+    assert_eq!(solution.id, "solutions/2026-02-19-fix-crlf-handling.md");
+    assert_eq!(solution.status.as_deref(), Some("active"));
+    assert!(solution.markdown_body.contains("## Root Cause"));
+
+    let brainstorm = create_doc_from_template_from_root(
+        &workspace,
+        &docs_root,
+        DocTemplateTarget::Brainstorm,
+        "Sidebar ideas",
+        None,
+        None,
+        test_date(),
+    )
+    .expect("brainstorm should use the built-in template");
+    assert_eq!(brainstorm.id, "brainstorms/2026-02-19-sidebar-ideas.md");
+    assert_eq!(brainstorm.title.as_deref(), Some("Sidebar ideas"));
+    assert!(brainstorm.markdown_body.contains("## Open Questions"));
+
+    teardown_workspace(&workspace);
+}
@@ -1,23 +1,44 @@
-use crate::project_registry::ProjectContext;
+use crate::doc_content_hash::{DocContentChange, DocContentHash, DocContentHashes};
+use crate::doc_format::doc_format_for_path;
+use crate::doc_git_status::DocGitStatusState;
+use crate::doc_ignore::{is_ignore_file, DocIgnoreRules};
+use crate::doc_scan::{collect_doc_paths, never_cancelled};
+use crate::doc_summary_cache::DocSummaryCacheState;
+use crate::project_registry::{
+    resolve_scoped_doc_id, DocsRoot, ProjectContext, WatcherBackend, WatcherSettings,
+};
 use crate::project_runtime::ProjectRegistryState;
 use notify::event::ModifyKind;
-use notify::{Event, EventKind, RecursiveMode, Watcher};
+use notify::{Event, EventKind, PollWatcher, RecursiveMode, Watcher};
 use serde::Serialize;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::io::Error;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::RecvTimeoutError;
 use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
-use std::thread::JoinHandle;
-use std::time::Duration;
-use tauri::{AppHandle, Emitter};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
-const DOC_FILE_EXTENSION: &str = "md";
 const WATCH_DEBOUNCE_WINDOW: Duration = Duration::from_millis(180);
-const WATCH_STOP_POLL_WINDOW: Duration = Duration::from_millis(120);
+const WATCHER_THREAD_NAME: &str = "coda-docs-watcher";
+const DOCS_ROOT_RETRY_INITIAL_DELAY: Duration = Duration::from_millis(250);
+const DOCS_ROOT_RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
 pub const DOCS_CHANGED_EVENT: &str = "docs_changed";
+pub const DOCS_ROOT_UNAVAILABLE_EVENT: &str = "docs_root_unavailable";
+pub const DOCS_ROOT_RESTORED_EVENT: &str = "docs_root_restored";
+pub const WATCHER_STATUS_CHANGED_EVENT: &str = "watcher_status_changed";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocsWatcherRunState {
+    Running,
+    #[default]
+    Stopped,
+    Errored,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -36,21 +57,101 @@ pub struct DocsChangedEventPayload {
     changed_doc_ids: Vec<String>,
     removed_doc_ids: Vec<String>,
     kinds: Vec<DocsChangeKind>,
+    moved: Vec<DocMove>,
+    content_changes: Vec<DocContentChange>,
+    full_resync: bool,
+    unread_change_count: usize,
     emitted_at_iso: String,
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DocMove {
+    from: String,
+    to: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocsRootAvailabilityPayload {
+    project_id: String,
+    namespace: Option<String>,
+    docs_root: String,
+    emitted_at_iso: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnreadDocChanges {
+    project_id: String,
+    unread_change_count: usize,
+}
+
 #[derive(Debug, Default)]
 struct DocsChangeAccumulator {
     changed_doc_ids: BTreeSet<String>,
     removed_doc_ids: BTreeSet<String>,
+    moved: BTreeSet<DocMove>,
     kinds: BTreeSet<DocsChangeKind>,
 }
 
+#[derive(Debug, Clone)]
+struct WatchedProject {
+    project_id: String,
+    workspace_root: PathBuf,
+    docs_roots: Vec<DocsRoot>,
+    docs_exclude: Vec<String>,
+    ignore_rules: Arc<DocIgnoreRules>,
+}
+
+struct ActiveWatcher {
+    watcher: Box<dyn Watcher + Send>,
+    backend: WatcherBackend,
+    settings: WatcherSettings,
+    message_sender: mpsc::Sender<WatcherMessage>,
+    watched_paths: BTreeSet<PathBuf>,
+    unavailable_roots: BTreeMap<PathBuf, DocsRootRetry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DocsRootRetry {
+    attempts: u32,
+    next_attempt_at: Instant,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DocsWatcherStatus {
+    state: DocsWatcherRunState,
+    backend: Option<WatcherBackend>,
+    configured_backend: WatcherBackend,
+    poll_interval_ms: u64,
+    project_id: Option<String>,
+    last_event_at_iso: Option<String>,
+    dropped_event_count: u64,
+    last_error: Option<String>,
+    errored_project_ids: Vec<String>,
+}
+
+enum WatcherMessage {
+    Event(notify::Result<Event>),
+    SyncProjects,
+    Stop,
+}
+
 #[derive(Default)]
 struct DocsWatcherRuntime {
-    stop_sender: Option<mpsc::Sender<()>>,
-    watcher_thread: Option<JoinHandle<()>>,
+    message_sender: Option<mpsc::Sender<WatcherMessage>>,
+    thread_generation: u64,
+    settings: WatcherSettings,
+    state: DocsWatcherRunState,
+    backend: Option<WatcherBackend>,
+    last_event_at_iso: Option<String>,
+    dropped_event_count: u64,
+    last_error: Option<String>,
+    errored_project_ids: BTreeSet<String>,
+    projects: BTreeMap<String, WatchedProject>,
     active_project_id: Option<String>,
+    unread_change_counts: BTreeMap<String, usize>,
 }
 
 #[derive(Clone, Default)]
@@ -63,12 +164,56 @@ pub fn start_docs_watcher(
     watcher_state: &DocsWatcherState,
     project_state: &ProjectRegistryState,
 ) -> Result<(), Error> {
+    let settings = project_state.watcher_settings().map_err(Error::other)?;
+    let projects = project_state.project_contexts().map_err(Error::other)?;
     let active_project = project_state
         .active_project_context()
         .map_err(Error::other)?;
-    watcher_state
-        .switch_to_project(app_handle, &active_project)
-        .map_err(Error::other)
+
+    watcher_state.configure(settings).map_err(Error::other)?;
+    for project in &projects {
+        if let Err(error) = watcher_state.watch_project(app_handle.clone(), project) {
+            watcher_state.record_project_error(&project.project_id, error);
+        }
+    }
+    if let Err(error) = watcher_state.switch_to_project(app_handle.clone(), &active_project) {
+        watcher_state.record_project_error(&active_project.project_id, error);
+        watcher_state.emit_status(&app_handle);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_watcher_status(
+    watcher_state: State<'_, DocsWatcherState>,
+) -> Result<DocsWatcherStatus, String> {
+    watcher_state.status()
+}
+
+#[tauri::command]
+pub fn restart_docs_watcher(
+    app_handle: AppHandle,
+    watcher_state: State<'_, DocsWatcherState>,
+    registry_state: State<'_, ProjectRegistryState>,
+) -> Result<DocsWatcherStatus, String> {
+    watcher_state.configure(registry_state.watcher_settings()?)?;
+    watcher_state.restart(app_handle)
+}
+
+#[tauri::command]
+pub fn list_unread_doc_changes(
+    watcher_state: State<'_, DocsWatcherState>,
+) -> Result<Vec<UnreadDocChanges>, String> {
+    watcher_state.unread_doc_changes()
+}
+
+#[tauri::command]
+pub fn mark_doc_changes_read(
+    project_id: String,
+    watcher_state: State<'_, DocsWatcherState>,
+) -> Result<(), String> {
+    watcher_state.mark_doc_changes_read(&project_id)
 }
 
 impl DocsWatcherState {
@@ -83,133 +228,854 @@ impl DocsWatcherState {
         app_handle: AppHandle,
         project: &ProjectContext,
     ) -> Result<(), String> {
-        let docs_root = project.docs_path.canonicalize().map_err(|error| {
-            format!(
-                "docs watcher switch failed: cannot resolve docs root {}: {error}",
-                project.docs_path.display()
-            )
-        })?;
+        self.watch_project(app_handle.clone(), project)?;
 
-        let (previous_stop_sender, previous_thread) = {
-            let mut runtime = self
-                .inner
-                .lock()
-                .map_err(|_| "docs watcher state lock poisoned".to_string())?;
+        {
+            let mut runtime = self.lock_runtime()?;
+            runtime.active_project_id = Some(project.project_id.clone());
+            runtime.unread_change_counts.remove(&project.project_id);
+        }
+        self.emit_status(&app_handle);
 
-            if runtime.active_project_id.as_deref() == Some(project.project_id.as_str()) {
-                return Ok(());
-            }
+        Ok(())
+    }
 
-            (runtime.stop_sender.take(), runtime.watcher_thread.take())
-        };
+    pub fn watch_project(
+        &self,
+        app_handle: AppHandle,
+        project: &ProjectContext,
+    ) -> Result<(), String> {
+        let watched_project = WatchedProject::from_context(project)?;
+        let message_sender = self.ensure_watcher_thread(app_handle)?;
 
-        if let Some(stop_sender) = previous_stop_sender {
-            let _ = stop_sender.send(());
+        {
+            let mut runtime = self.lock_runtime()?;
+            runtime
+                .errored_project_ids
+                .remove(&watched_project.project_id);
+            runtime
+                .projects
+                .insert(watched_project.project_id.clone(), watched_project);
         }
+        message_sender
+            .send(WatcherMessage::SyncProjects)
+            .map_err(|_| "docs watcher thread is not running".to_string())
+    }
+
+    pub fn unwatch_project(&self, project_id: &str) -> Result<(), String> {
+        let message_sender = {
+            let mut runtime = self.lock_runtime()?;
+            runtime.projects.remove(project_id);
+            runtime.errored_project_ids.remove(project_id);
+            runtime.unread_change_counts.remove(project_id);
+            runtime.message_sender.clone()
+        };
 
-        if let Some(previous_thread) = previous_thread {
-            let _ = previous_thread.join();
+        if let Some(message_sender) = message_sender {
+            if message_sender.send(WatcherMessage::SyncProjects).is_err() {
+                log::warn!("docs watcher thread stopped before unwatching {project_id}");
+            }
         }
 
-        let project_id = project.project_id.clone();
-        let docs_root_for_thread = docs_root.clone();
-        let app_handle_for_thread = app_handle.clone();
-        let (stop_sender, stop_receiver) = mpsc::channel::<()>();
+        Ok(())
+    }
+
+    pub fn configure(&self, settings: WatcherSettings) -> Result<(), String> {
+        self.lock_runtime()?.settings = settings;
+        Ok(())
+    }
+
+    pub fn status(&self) -> Result<DocsWatcherStatus, String> {
+        let runtime = self.lock_runtime()?;
+
+        Ok(DocsWatcherStatus {
+            state: runtime.state,
+            backend: runtime.backend,
+            configured_backend: runtime.settings.backend,
+            poll_interval_ms: runtime.settings.poll_interval.as_millis() as u64,
+            project_id: runtime.active_project_id.clone(),
+            last_event_at_iso: runtime.last_event_at_iso.clone(),
+            dropped_event_count: runtime.dropped_event_count,
+            last_error: runtime.last_error.clone(),
+            errored_project_ids: runtime.errored_project_ids.iter().cloned().collect(),
+        })
+    }
+
+    pub fn restart(&self, app_handle: AppHandle) -> Result<DocsWatcherStatus, String> {
+        self.stop_watcher_thread()?;
+
+        self.ensure_watcher_thread(app_handle)?
+            .send(WatcherMessage::SyncProjects)
+            .map_err(|_| {
+                "docs watcher restart failed: watcher thread is not running".to_string()
+            })?;
+        self.status()
+    }
+
+    pub fn unread_doc_changes(&self) -> Result<Vec<UnreadDocChanges>, String> {
+        Ok(self
+            .lock_runtime()?
+            .unread_change_counts
+            .iter()
+            .map(|(project_id, unread_change_count)| UnreadDocChanges {
+                project_id: project_id.clone(),
+                unread_change_count: *unread_change_count,
+            })
+            .collect())
+    }
+
+    pub fn mark_doc_changes_read(&self, project_id: &str) -> Result<(), String> {
+        self.lock_runtime()?.unread_change_counts.remove(project_id);
+        Ok(())
+    }
 
-        let thread_name = format!("coda-docs-watcher-{}", project_id);
-        let thread_project_id = project_id.clone();
+    fn ensure_watcher_thread(
+        &self,
+        app_handle: AppHandle,
+    ) -> Result<mpsc::Sender<WatcherMessage>, String> {
+        let mut runtime = self.lock_runtime()?;
+        if let Some(message_sender) = &runtime.message_sender {
+            return Ok(message_sender.clone());
+        }
 
-        let watcher_thread = thread::Builder::new()
-            .name(thread_name)
+        let (message_sender, message_receiver) = mpsc::channel::<WatcherMessage>();
+        let thread_state = self.clone();
+        let thread_message_sender = message_sender.clone();
+        let thread_generation = runtime.thread_generation;
+
+        thread::Builder::new()
+            .name(WATCHER_THREAD_NAME.to_string())
             .spawn(move || {
-                if let Err(error) = watch_docs_loop(
-                    &app_handle_for_thread,
-                    &thread_project_id,
-                    &docs_root_for_thread,
-                    stop_receiver,
-                ) {
-                    log::error!("docs watcher stopped for project {}: {}", thread_project_id, error);
+                let result = watch_docs_loop(
+                    &app_handle,
+                    &thread_state,
+                    thread_message_sender,
+                    message_receiver,
+                );
+                if let Err(error) = &result {
+                    log::error!("docs watcher stopped: {error}");
+                }
+
+                let Ok(mut runtime) = thread_state.inner.lock() else {
+                    return;
+                };
+                if runtime.thread_generation != thread_generation {
+                    return;
+                }
+                runtime.message_sender = None;
+                runtime.backend = None;
+                match result {
+                    Ok(()) => runtime.state = DocsWatcherRunState::Stopped,
+                    Err(error) => {
+                        runtime.state = DocsWatcherRunState::Errored;
+                        runtime.last_error = Some(error.to_string());
+                    }
                 }
+                drop(runtime);
+                thread_state.emit_status(&app_handle);
             })
             .map_err(|error| {
-                format!(
-                    "docs watcher switch failed: cannot spawn watcher thread for project {}: {error}",
-                    project_id
-                )
+                format!("docs watcher start failed: cannot spawn watcher thread: {error}")
             })?;
 
-        let mut runtime = self
-            .inner
+        runtime.message_sender = Some(message_sender.clone());
+
+        Ok(message_sender)
+    }
+
+    fn stop_watcher_thread(&self) -> Result<(), String> {
+        let previous_sender = {
+            let mut runtime = self.lock_runtime()?;
+            runtime.thread_generation += 1;
+            runtime.state = DocsWatcherRunState::Stopped;
+            runtime.backend = None;
+            runtime.last_error = None;
+            runtime.message_sender.take()
+        };
+        if let Some(previous_sender) = previous_sender {
+            let _ = previous_sender.send(WatcherMessage::Stop);
+        }
+
+        Ok(())
+    }
+
+    fn lock_runtime(&self) -> Result<std::sync::MutexGuard<'_, DocsWatcherRuntime>, String> {
+        self.inner
             .lock()
-            .map_err(|_| "docs watcher state lock poisoned".to_string())?;
+            .map_err(|_| "docs watcher state lock poisoned".to_string())
+    }
 
-        runtime.stop_sender = Some(stop_sender);
-        runtime.watcher_thread = Some(watcher_thread);
-        runtime.active_project_id = Some(project_id);
+    fn settings(&self) -> Result<WatcherSettings, String> {
+        Ok(self.lock_runtime()?.settings)
+    }
+
+    fn set_backend(&self, backend: Option<WatcherBackend>) -> Result<(), String> {
+        self.lock_runtime()?.backend = backend;
+        Ok(())
+    }
+
+    fn mark_running(&self, backend: WatcherBackend) -> Result<(), String> {
+        let mut runtime = self.lock_runtime()?;
+        runtime.state = DocsWatcherRunState::Running;
+        runtime.backend = Some(backend);
+        Ok(())
+    }
+
+    fn record_project_error(&self, project_id: &str, error: String) {
+        log::warn!("docs watcher cannot watch project {project_id}: {error}");
+        let Ok(mut runtime) = self.lock_runtime() else {
+            return;
+        };
+        runtime.errored_project_ids.insert(project_id.to_string());
+        runtime.last_error = Some(error);
+    }
+
+    fn refresh_ignore_rules(&self, events: &[notify::Result<Event>]) -> Result<(), String> {
+        let ignore_file_paths = events
+            .iter()
+            .filter_map(|event| event.as_ref().ok())
+            .flat_map(|event| &event.paths)
+            .filter(|path| is_ignore_file(path))
+            .collect::<Vec<&PathBuf>>();
+        if ignore_file_paths.is_empty() {
+            return Ok(());
+        }
+
+        let mut runtime = self.lock_runtime()?;
+        for project in runtime.projects.values_mut() {
+            let touches_project = ignore_file_paths.iter().any(|path| {
+                project
+                    .docs_roots
+                    .iter()
+                    .any(|docs_root| path.starts_with(&docs_root.path))
+            });
+            if touches_project {
+                project.ignore_rules = Arc::new(project.load_ignore_rules());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn record_events(&self, events: &[notify::Result<Event>]) -> Result<bool, String> {
+        let mut runtime = self.lock_runtime()?;
+        runtime.last_event_at_iso = Some(now_iso_utc());
+
+        let mut dropped = false;
+        for error in events.iter().filter_map(|event| event.as_ref().err()) {
+            log::warn!("docs watcher event dropped: {error}");
+            runtime.dropped_event_count += 1;
+            runtime.last_error = Some(error.to_string());
+            dropped = true;
+        }
+
+        Ok(dropped)
+    }
+
+    fn emit_status(&self, app_handle: &AppHandle) {
+        let emitted = self.status().and_then(|status| {
+            app_handle
+                .emit(WATCHER_STATUS_CHANGED_EVENT, status)
+                .map_err(|error| error.to_string())
+        });
+        if let Err(error) = emitted {
+            log::warn!("docs watcher status emit failed: {error}");
+        }
+    }
+
+    fn watched_projects(&self) -> Result<Vec<WatchedProject>, String> {
+        Ok(self.lock_runtime()?.projects.values().cloned().collect())
+    }
+
+    fn record_unread_changes(&self, payload: &mut DocsChangedEventPayload) -> Result<(), String> {
+        let mut runtime = self.lock_runtime()?;
+        if runtime.active_project_id.as_deref() == Some(payload.project_id.as_str()) {
+            return Ok(());
+        }
+
+        let unread_change_count = runtime
+            .unread_change_counts
+            .entry(payload.project_id.clone())
+            .or_default();
+        *unread_change_count += payload.changed_doc_ids.len() + payload.removed_doc_ids.len();
+        payload.unread_change_count = *unread_change_count;
 
         Ok(())
     }
 }
 
-fn watch_docs_loop(
-    app_handle: &AppHandle,
-    project_id: &str,
-    docs_root: &Path,
-    stop_receiver: mpsc::Receiver<()>,
-) -> Result<(), Error> {
-    let (event_sender, event_receiver) = mpsc::channel::<notify::Result<Event>>();
+impl WatchedProject {
+    fn from_context(project: &ProjectContext) -> Result<Self, String> {
+        let docs_roots = project
+            .docs_roots
+            .iter()
+            .map(|docs_root| {
+                docs_root
+                    .path
+                    .canonicalize()
+                    .map(|path| DocsRoot {
+                        namespace: docs_root.namespace.clone(),
+                        path,
+                    })
+                    .map_err(|error| {
+                        format!(
+                            "docs watcher failed: cannot resolve docs root {}: {error}",
+                            docs_root.path.display()
+                        )
+                    })
+            })
+            .collect::<Result<Vec<DocsRoot>, String>>()?;
+        let workspace_root = project.root_path.canonicalize().map_err(|error| {
+            format!(
+                "docs watcher failed: cannot resolve workspace root {}: {error}",
+                project.root_path.display()
+            )
+        })?;
 
-    let mut watcher = notify::recommended_watcher(move |event| {
-        if event_sender.send(event).is_err() {
-            log::warn!("docs watcher receiver dropped");
+        let mut watched_project = Self {
+            project_id: project.project_id.clone(),
+            workspace_root,
+            docs_roots,
+            docs_exclude: project.docs_exclude.clone(),
+            ignore_rules: Arc::default(),
+        };
+        watched_project.ignore_rules = Arc::new(watched_project.load_ignore_rules());
+
+        Ok(watched_project)
+    }
+
+    fn load_ignore_rules(&self) -> DocIgnoreRules {
+        let docs_root_paths = self
+            .docs_roots
+            .iter()
+            .map(|docs_root| docs_root.path.clone())
+            .collect::<Vec<PathBuf>>();
+
+        DocIgnoreRules::load(&self.workspace_root, &docs_root_paths, &self.docs_exclude)
+    }
+}
+
+impl DocsChangedEventPayload {
+    fn full_resync(project_id: &str) -> Self {
+        Self {
+            project_id: project_id.to_string(),
+            changed_doc_ids: Vec::new(),
+            removed_doc_ids: Vec::new(),
+            kinds: Vec::new(),
+            moved: Vec::new(),
+            content_changes: Vec::new(),
+            full_resync: true,
+            unread_change_count: 0,
+            emitted_at_iso: now_iso_utc(),
+        }
+    }
+
+    fn doc_paths(&self, docs_roots: &[DocsRoot]) -> Vec<PathBuf> {
+        self.changed_doc_ids
+            .iter()
+            .chain(&self.removed_doc_ids)
+            .filter_map(|doc_id| resolve_scoped_doc_id(docs_roots, doc_id))
+            .map(|(docs_root, local_doc_id)| docs_root.path.join(local_doc_id))
+            .collect()
+    }
+
+    fn drop_unchanged_content(
+        &mut self,
+        docs_roots: &[DocsRoot],
+        content_hashes: &mut DocContentHashes,
+    ) -> bool {
+        for doc_id in &self.removed_doc_ids {
+            content_hashes.remove(doc_id);
         }
-    })
-    .map_err(Error::other)?;
 
-    watcher
-        .watch(docs_root, RecursiveMode::Recursive)
+        let mut content_changes = Vec::new();
+        self.changed_doc_ids.retain(|doc_id| {
+            let content_hash =
+                resolve_scoped_doc_id(docs_roots, doc_id).and_then(|(docs_root, local_doc_id)| {
+                    DocContentHash::read(&docs_root.path.join(local_doc_id))
+                });
+            let Some(content_change) = content_hashes.record(doc_id, content_hash) else {
+                return false;
+            };
+
+            content_changes.push(content_change);
+            true
+        });
+        self.content_changes = content_changes;
+
+        !self.changed_doc_ids.is_empty() || !self.removed_doc_ids.is_empty()
+    }
+}
+
+fn watch_docs_loop(
+    app_handle: &AppHandle,
+    watcher_state: &DocsWatcherState,
+    message_sender: mpsc::Sender<WatcherMessage>,
+    message_receiver: mpsc::Receiver<WatcherMessage>,
+) -> Result<(), Error> {
+    let settings = watcher_state.settings().map_err(Error::other)?;
+    let mut watcher = ActiveWatcher::start(settings, message_sender)?;
+    let mut content_hashes = HashMap::new();
+    watcher_state
+        .mark_running(watcher.backend)
         .map_err(Error::other)?;
+    watcher_state.emit_status(app_handle);
 
     loop {
-        if stop_receiver.try_recv().is_ok() {
+        let restored_roots = watcher.restore_unavailable_roots(Instant::now());
+        emit_docs_root_availability(
+            app_handle,
+            watcher_state,
+            &mut content_hashes,
+            &restored_roots,
+            DOCS_ROOT_RESTORED_EVENT,
+        )?;
+
+        let first_message = match watcher.next_root_retry_delay(Instant::now()) {
+            Some(retry_delay) => match message_receiver.recv_timeout(retry_delay) {
+                Ok(message) => message,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            },
+            None => match message_receiver.recv() {
+                Ok(message) => message,
+                Err(_) => return Ok(()),
+            },
+        };
+        let first_event = match first_message {
+            WatcherMessage::Event(event) => event,
+            WatcherMessage::SyncProjects => {
+                watcher.sync_watched_paths(watcher_state)?;
+                sync_content_hashes(watcher_state, &mut content_hashes)?;
+                watcher_state.emit_status(app_handle);
+                continue;
+            }
+            WatcherMessage::Stop => return Ok(()),
+        };
+
+        let mut pending_events = vec![first_event];
+        let mut sync_requested = false;
+        let mut stop_requested = false;
+
+        while let Ok(message) = message_receiver.recv_timeout(WATCH_DEBOUNCE_WINDOW) {
+            match message {
+                WatcherMessage::Event(event) => pending_events.push(event),
+                WatcherMessage::SyncProjects => sync_requested = true,
+                WatcherMessage::Stop => {
+                    stop_requested = true;
+                    break;
+                }
+            }
+        }
+        if stop_requested {
             return Ok(());
         }
 
-        let first_event = match event_receiver.recv_timeout(WATCH_STOP_POLL_WINDOW) {
-            Ok(event) => event,
-            Err(mpsc::RecvTimeoutError::Timeout) => continue,
-            Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+        if watcher_state
+            .record_events(&pending_events)
+            .map_err(Error::other)?
+        {
+            watcher_state.emit_status(app_handle);
+        }
+        watcher_state
+            .refresh_ignore_rules(&pending_events)
+            .map_err(Error::other)?;
+
+        let unavailable_roots = watcher.detect_unavailable_roots(&pending_events, Instant::now());
+        emit_docs_root_availability(
+            app_handle,
+            watcher_state,
+            &mut content_hashes,
+            &unavailable_roots,
+            DOCS_ROOT_UNAVAILABLE_EVENT,
+        )?;
+        emit_docs_changed(
+            app_handle,
+            watcher_state,
+            &mut content_hashes,
+            &watcher.unavailable_root_paths(),
+            &pending_events,
+        )?;
+
+        if sync_requested {
+            watcher.sync_watched_paths(watcher_state)?;
+            sync_content_hashes(watcher_state, &mut content_hashes)?;
+            watcher_state.emit_status(app_handle);
+        }
+    }
+}
+
+impl ActiveWatcher {
+    fn start(
+        settings: WatcherSettings,
+        message_sender: mpsc::Sender<WatcherMessage>,
+    ) -> Result<Self, Error> {
+        if settings.backend == WatcherBackend::Native {
+            match notify::recommended_watcher(forward_events(message_sender.clone())) {
+                Ok(watcher) => {
+                    return Ok(Self {
+                        watcher: Box::new(watcher),
+                        backend: WatcherBackend::Native,
+                        settings,
+                        message_sender,
+                        watched_paths: BTreeSet::new(),
+                        unavailable_roots: BTreeMap::new(),
+                    })
+                }
+                Err(error) => {
+                    log::warn!("native docs watcher unavailable, falling back to polling: {error}")
+                }
+            }
+        }
+
+        Self::start_polling(settings, message_sender)
+    }
+
+    fn start_polling(
+        settings: WatcherSettings,
+        message_sender: mpsc::Sender<WatcherMessage>,
+    ) -> Result<Self, Error> {
+        let config = notify::Config::default().with_poll_interval(settings.poll_interval);
+        let watcher = PollWatcher::new(forward_events(message_sender.clone()), config)
+            .map_err(Error::other)?;
+
+        Ok(Self {
+            watcher: Box::new(watcher),
+            backend: WatcherBackend::Poll,
+            settings,
+            message_sender,
+            watched_paths: BTreeSet::new(),
+            unavailable_roots: BTreeMap::new(),
+        })
+    }
+
+    fn fall_back_to_polling(&mut self) -> Result<(), Error> {
+        *self = Self::start_polling(self.settings, self.message_sender.clone())?;
+        Ok(())
+    }
+
+    fn sync_watched_paths(&mut self, watcher_state: &DocsWatcherState) -> Result<(), Error> {
+        let desired_paths = watcher_state
+            .watched_projects()
+            .map_err(Error::other)?
+            .into_iter()
+            .flat_map(|project| project.docs_roots)
+            .map(|docs_root| docs_root.path)
+            .collect::<BTreeSet<PathBuf>>();
+
+        for path in self.watched_paths.difference(&desired_paths) {
+            if let Err(error) = self.watcher.unwatch(path) {
+                log::warn!("docs watcher cannot unwatch {}: {error}", path.display());
+            }
+        }
+
+        self.watched_paths
+            .retain(|path| desired_paths.contains(path));
+        self.unavailable_roots
+            .retain(|path, _| desired_paths.contains(path));
+        for path in desired_paths {
+            if self.watched_paths.contains(&path) || self.unavailable_roots.contains_key(&path) {
+                continue;
+            }
+            if !path.is_dir() {
+                log::warn!("docs root {} is unavailable, retrying", path.display());
+                self.unavailable_roots
+                    .insert(path, DocsRootRetry::after_attempts(0, Instant::now()));
+                continue;
+            }
+
+            match self.watcher.watch(&path, RecursiveMode::Recursive) {
+                Ok(()) => {
+                    self.watched_paths.insert(path);
+                }
+                Err(error) if self.backend == WatcherBackend::Native => {
+                    log::warn!(
+                        "native docs watcher cannot watch {}, falling back to polling: {error}",
+                        path.display()
+                    );
+                    self.fall_back_to_polling()?;
+                    watcher_state
+                        .set_backend(Some(self.backend))
+                        .map_err(Error::other)?;
+                    return self.sync_watched_paths(watcher_state);
+                }
+                Err(error) => log::warn!("docs watcher cannot watch {}: {error}", path.display()),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ActiveWatcher {
+    fn unavailable_root_paths(&self) -> BTreeSet<PathBuf> {
+        self.unavailable_roots.keys().cloned().collect()
+    }
+
+    fn next_root_retry_delay(&self, now: Instant) -> Option<Duration> {
+        self.unavailable_roots
+            .values()
+            .map(|retry| retry.next_attempt_at.saturating_duration_since(now))
+            .min()
+    }
+
+    fn detect_unavailable_roots(
+        &mut self,
+        events: &[notify::Result<Event>],
+        now: Instant,
+    ) -> Vec<PathBuf> {
+        let removed_paths = events
+            .iter()
+            .filter_map(|event| event.as_ref().ok())
+            .filter(|event| matches!(event.kind, EventKind::Remove(_)))
+            .flat_map(|event| &event.paths)
+            .collect::<BTreeSet<&PathBuf>>();
+        let unavailable_roots = self
+            .watched_paths
+            .iter()
+            .filter(|path| removed_paths.contains(path) || !path.is_dir())
+            .cloned()
+            .collect::<Vec<PathBuf>>();
+
+        for path in &unavailable_roots {
+            log::warn!("docs root {} became unavailable, retrying", path.display());
+            if let Err(error) = self.watcher.unwatch(path) {
+                log::debug!("docs watcher cannot unwatch {}: {error}", path.display());
+            }
+            self.watched_paths.remove(path);
+            self.unavailable_roots
+                .insert(path.clone(), DocsRootRetry::after_attempts(0, now));
+        }
+
+        unavailable_roots
+    }
+
+    fn restore_unavailable_roots(&mut self, now: Instant) -> Vec<PathBuf> {
+        let mut restored_roots = Vec::new();
+
+        for (path, retry) in &mut self.unavailable_roots {
+            if retry.next_attempt_at > now {
+                continue;
+            }
+
+            let watched = path.is_dir()
+                && match self.watcher.watch(path, RecursiveMode::Recursive) {
+                    Ok(()) => true,
+                    Err(error) => {
+                        log::warn!("docs watcher cannot rewatch {}: {error}", path.display());
+                        false
+                    }
+                };
+            if watched {
+                log::info!("docs root {} restored", path.display());
+                restored_roots.push(path.clone());
+            } else {
+                *retry = DocsRootRetry::after_attempts(retry.attempts + 1, now);
+            }
+        }
+
+        for path in &restored_roots {
+            self.unavailable_roots.remove(path);
+            self.watched_paths.insert(path.clone());
+        }
+
+        restored_roots
+    }
+}
+
+impl DocsRootRetry {
+    fn after_attempts(attempts: u32, now: Instant) -> Self {
+        let delay = DOCS_ROOT_RETRY_INITIAL_DELAY
+            .saturating_mul(2_u32.saturating_pow(attempts))
+            .min(DOCS_ROOT_RETRY_MAX_DELAY);
+
+        Self {
+            attempts,
+            next_attempt_at: now + delay,
+        }
+    }
+}
+
+fn forward_events(
+    message_sender: mpsc::Sender<WatcherMessage>,
+) -> impl FnMut(notify::Result<Event>) + Send + 'static {
+    move |event| {
+        if message_sender.send(WatcherMessage::Event(event)).is_err() {
+            log::warn!("docs watcher receiver dropped");
+        }
+    }
+}
+
+fn sync_content_hashes(
+    watcher_state: &DocsWatcherState,
+    content_hashes: &mut HashMap<String, DocContentHashes>,
+) -> Result<(), Error> {
+    let projects = watcher_state.watched_projects().map_err(Error::other)?;
+    content_hashes.retain(|project_id, _| {
+        projects
+            .iter()
+            .any(|project| project.project_id == *project_id)
+    });
+
+    for project in projects {
+        content_hashes
+            .entry(project.project_id.clone())
+            .or_insert_with(|| seed_content_hashes(&project));
+    }
+
+    Ok(())
+}
+
+fn seed_content_hashes(project: &WatchedProject) -> DocContentHashes {
+    let ignore_rules = &project.ignore_rules;
+    let mut content_hashes = DocContentHashes::default();
+
+    for docs_root in &project.docs_roots {
+        let doc_paths = match collect_doc_paths(&docs_root.path, ignore_rules, &never_cancelled) {
+            Ok(doc_paths) => doc_paths,
+            Err(error) => {
+                log::warn!(
+                    "docs watcher cannot hash docs in {}: {error}",
+                    docs_root.path.display()
+                );
+                continue;
+            }
         };
 
-        let mut pending_events = vec![first_event];
+        for doc_path in doc_paths {
+            let doc_id = path_to_doc_id(&doc_path, &project.docs_roots, ignore_rules);
+            if let (Some(doc_id), Some(content_hash)) = (doc_id, DocContentHash::read(&doc_path)) {
+                content_hashes.insert(doc_id, content_hash);
+            }
+        }
+    }
 
-        while let Ok(event) = event_receiver.recv_timeout(WATCH_DEBOUNCE_WINDOW) {
-            pending_events.push(event);
-            if stop_receiver.try_recv().is_ok() {
-                return Ok(());
+    content_hashes
+}
+
+fn emit_docs_changed(
+    app_handle: &AppHandle,
+    watcher_state: &DocsWatcherState,
+    content_hashes: &mut HashMap<String, DocContentHashes>,
+    unavailable_roots: &BTreeSet<PathBuf>,
+    events: &[notify::Result<Event>],
+) -> Result<(), Error> {
+    for project in watcher_state.watched_projects().map_err(Error::other)? {
+        if project
+            .docs_roots
+            .iter()
+            .any(|docs_root| unavailable_roots.contains(&docs_root.path))
+        {
+            continue;
+        }
+        let project_content_hashes = content_hashes
+            .entry(project.project_id.clone())
+            .or_default();
+        let Some(mut payload) = build_docs_changed_payload(
+            &project.project_id,
+            &project.docs_roots,
+            &project.ignore_rules,
+            project_content_hashes,
+            events,
+        ) else {
+            continue;
+        };
+        if !payload.drop_unchanged_content(&project.docs_roots, project_content_hashes) {
+            continue;
+        }
+
+        if let Some(git_status_state) = app_handle.try_state::<DocGitStatusState>() {
+            for docs_root in &project.docs_roots {
+                git_status_state.invalidate(&docs_root.path);
             }
         }
+        if let Some(summary_cache_state) = app_handle.try_state::<DocSummaryCacheState>() {
+            summary_cache_state
+                .invalidate_doc_paths(&project.project_id, &payload.doc_paths(&project.docs_roots));
+        }
+
+        watcher_state
+            .record_unread_changes(&mut payload)
+            .map_err(Error::other)?;
+        app_handle
+            .emit(DOCS_CHANGED_EVENT, payload)
+            .map_err(Error::other)?;
+    }
+
+    Ok(())
+}
 
-        if let Some(payload) = build_docs_changed_payload(project_id, docs_root, &pending_events) {
+fn emit_docs_root_availability(
+    app_handle: &AppHandle,
+    watcher_state: &DocsWatcherState,
+    content_hashes: &mut HashMap<String, DocContentHashes>,
+    root_paths: &[PathBuf],
+    event_name: &str,
+) -> Result<(), Error> {
+    if root_paths.is_empty() {
+        return Ok(());
+    }
+
+    for project in watcher_state.watched_projects().map_err(Error::other)? {
+        let affected_roots = project
+            .docs_roots
+            .iter()
+            .filter(|docs_root| root_paths.contains(&docs_root.path))
+            .collect::<Vec<&DocsRoot>>();
+        if affected_roots.is_empty() {
+            continue;
+        }
+
+        for docs_root in affected_roots {
             app_handle
-                .emit(DOCS_CHANGED_EVENT, payload)
+                .emit(
+                    event_name,
+                    DocsRootAvailabilityPayload {
+                        project_id: project.project_id.clone(),
+                        namespace: docs_root.namespace.clone(),
+                        docs_root: docs_root.path.display().to_string(),
+                        emitted_at_iso: now_iso_utc(),
+                    },
+                )
                 .map_err(Error::other)?;
         }
+
+        content_hashes.insert(project.project_id.clone(), seed_content_hashes(&project));
+        if let Some(git_status_state) = app_handle.try_state::<DocGitStatusState>() {
+            for docs_root in &project.docs_roots {
+                git_status_state.invalidate(&docs_root.path);
+            }
+        }
+
+        let mut payload = DocsChangedEventPayload::full_resync(&project.project_id);
+        watcher_state
+            .record_unread_changes(&mut payload)
+            .map_err(Error::other)?;
+        app_handle
+            .emit(DOCS_CHANGED_EVENT, payload)
+            .map_err(Error::other)?;
     }
+
+    Ok(())
 }
 
 fn build_docs_changed_payload(
     project_id: &str,
-    docs_root: &Path,
+    docs_roots: &[DocsRoot],
+    ignore_rules: &DocIgnoreRules,
+    known_doc_ids: &DocContentHashes,
     events: &[notify::Result<Event>],
 ) -> Option<DocsChangedEventPayload> {
     let mut accumulator = DocsChangeAccumulator::default();
 
-    for maybe_event in events {
-        match maybe_event {
-            Ok(event) => accumulate_event_change(docs_root, event, &mut accumulator),
-            Err(error) => log::warn!("docs watcher event dropped: {error}"),
-        }
+    for event in events.iter().filter_map(|event| event.as_ref().ok()) {
+        accumulate_event_change(
+            docs_roots,
+            ignore_rules,
+            known_doc_ids,
+            event,
+            &mut accumulator,
+        );
     }
 
     if accumulator.changed_doc_ids.is_empty() && accumulator.removed_doc_ids.is_empty() {
@@ -227,12 +1093,18 @@ fn build_docs_changed_payload(
         changed_doc_ids: accumulator.changed_doc_ids.into_iter().collect(),
         removed_doc_ids: accumulator.removed_doc_ids.into_iter().collect(),
         kinds: accumulator.kinds.into_iter().collect(),
+        moved: accumulator.moved.into_iter().collect(),
+        content_changes: Vec::new(),
+        full_resync: false,
+        unread_change_count: 0,
         emitted_at_iso: now_iso_utc(),
     })
 }
 
 fn accumulate_event_change(
-    docs_root: &Path,
+    docs_roots: &[DocsRoot],
+    ignore_rules: &DocIgnoreRules,
+    known_doc_ids: &DocContentHashes,
     event: &Event,
     accumulator: &mut DocsChangeAccumulator,
 ) {
@@ -240,30 +1112,45 @@ fn accumulate_event_change(
         EventKind::Create(_) => {
             accumulator.kinds.insert(DocsChangeKind::Created);
             for path in &event.paths {
-                if let Some(doc_id) = path_to_doc_id(path, docs_root) {
-                    accumulator.changed_doc_ids.insert(doc_id);
-                }
+                accumulator
+                    .changed_doc_ids
+                    .extend(created_doc_ids(path, docs_roots, ignore_rules));
             }
         }
         EventKind::Modify(ModifyKind::Name(_)) => {
             accumulator.kinds.insert(DocsChangeKind::Renamed);
-            let mut doc_ids = event
-                .paths
-                .iter()
-                .filter_map(|path| path_to_doc_id(path, docs_root));
-
-            if let Some(previous_doc_id) = doc_ids.next() {
-                accumulator.removed_doc_ids.insert(previous_doc_id);
+            if let [from_path, to_path] = event.paths.as_slice() {
+                accumulate_move(
+                    docs_roots,
+                    ignore_rules,
+                    known_doc_ids,
+                    from_path,
+                    to_path,
+                    accumulator,
+                );
+                return;
             }
 
-            for doc_id in doc_ids {
-                accumulator.changed_doc_ids.insert(doc_id);
+            for path in &event.paths {
+                if path.exists() {
+                    accumulator.changed_doc_ids.extend(created_doc_ids(
+                        path,
+                        docs_roots,
+                        ignore_rules,
+                    ));
+                } else {
+                    accumulator.removed_doc_ids.extend(
+                        removed_doc_ids(path, docs_roots, ignore_rules, known_doc_ids)
+                            .into_iter()
+                            .map(|(doc_id, _)| doc_id),
+                    );
+                }
             }
         }
         EventKind::Modify(_) => {
             accumulator.kinds.insert(DocsChangeKind::Modified);
             for path in &event.paths {
-                if let Some(doc_id) = path_to_doc_id(path, docs_root) {
+                if let Some(doc_id) = path_to_doc_id(path, docs_roots, ignore_rules) {
                     accumulator.changed_doc_ids.insert(doc_id);
                 }
             }
@@ -271,15 +1158,17 @@ fn accumulate_event_change(
         EventKind::Remove(_) => {
             accumulator.kinds.insert(DocsChangeKind::Removed);
             for path in &event.paths {
-                if let Some(doc_id) = path_to_doc_id(path, docs_root) {
-                    accumulator.removed_doc_ids.insert(doc_id);
-                }
+                accumulator.removed_doc_ids.extend(
+                    removed_doc_ids(path, docs_roots, ignore_rules, known_doc_ids)
+                        .into_iter()
+                        .map(|(doc_id, _)| doc_id),
+                );
             }
         }
         _ => {
             let mut included = false;
             for path in &event.paths {
-                if let Some(doc_id) = path_to_doc_id(path, docs_root) {
+                if let Some(doc_id) = path_to_doc_id(path, docs_roots, ignore_rules) {
                     accumulator.changed_doc_ids.insert(doc_id);
                     included = true;
                 }
@@ -292,17 +1181,134 @@ fn accumulate_event_change(
     }
 }
 
-fn path_to_doc_id(path: &Path, docs_root: &Path) -> Option<String> {
-    if path.extension().and_then(|value| value.to_str()) != Some(DOC_FILE_EXTENSION) {
+fn accumulate_move(
+    docs_roots: &[DocsRoot],
+    ignore_rules: &DocIgnoreRules,
+    known_doc_ids: &DocContentHashes,
+    from_path: &Path,
+    to_path: &Path,
+    accumulator: &mut DocsChangeAccumulator,
+) {
+    let from_doc_id = path_to_doc_id(from_path, docs_roots, ignore_rules);
+    let to_doc_id = path_to_doc_id(to_path, docs_roots, ignore_rules);
+    if from_doc_id.is_some() || to_doc_id.is_some() {
+        if let (Some(from), Some(to)) = (&from_doc_id, &to_doc_id) {
+            accumulator.moved.insert(DocMove {
+                from: from.clone(),
+                to: to.clone(),
+            });
+        }
+        accumulator.removed_doc_ids.extend(from_doc_id);
+        accumulator.changed_doc_ids.extend(to_doc_id);
+        return;
+    }
+
+    for (from_doc_id, relative_path) in
+        removed_doc_ids(from_path, docs_roots, ignore_rules, known_doc_ids)
+    {
+        if let Some(to_doc_id) =
+            path_to_doc_id(&to_path.join(relative_path), docs_roots, ignore_rules)
+        {
+            accumulator.moved.insert(DocMove {
+                from: from_doc_id.clone(),
+                to: to_doc_id.clone(),
+            });
+            accumulator.changed_doc_ids.insert(to_doc_id);
+        }
+        accumulator.removed_doc_ids.insert(from_doc_id);
+    }
+    accumulator
+        .changed_doc_ids
+        .extend(created_doc_ids(to_path, docs_roots, ignore_rules));
+}
+
+fn created_doc_ids(
+    path: &Path,
+    docs_roots: &[DocsRoot],
+    ignore_rules: &DocIgnoreRules,
+) -> Vec<String> {
+    if let Some(doc_id) = path_to_doc_id(path, docs_roots, ignore_rules) {
+        return vec![doc_id];
+    }
+    if !path.is_dir()
+        || ignore_rules.is_ignored(path, true)
+        || locate_in_docs_roots(path, docs_roots).is_none()
+    {
+        return Vec::new();
+    }
+
+    match collect_doc_paths(path, ignore_rules, &never_cancelled) {
+        Ok(doc_paths) => doc_paths
+            .iter()
+            .filter_map(|doc_path| path_to_doc_id(doc_path, docs_roots, ignore_rules))
+            .collect(),
+        Err(error) => {
+            log::warn!("docs watcher cannot scan {}: {error}", path.display());
+            Vec::new()
+        }
+    }
+}
+
+fn removed_doc_ids(
+    path: &Path,
+    docs_roots: &[DocsRoot],
+    ignore_rules: &DocIgnoreRules,
+    known_doc_ids: &DocContentHashes,
+) -> Vec<(String, String)> {
+    if let Some(doc_id) = path_to_doc_id(path, docs_roots, ignore_rules) {
+        return vec![(doc_id, String::new())];
+    }
+    let Some((docs_root, relative_path)) = locate_in_docs_roots(path, docs_roots) else {
+        return Vec::new();
+    };
+    let dir_prefix = format!("{}/", docs_root.scoped_doc_id(&relative_path));
+
+    let mut doc_ids = known_doc_ids
+        .doc_ids()
+        .filter_map(|doc_id| {
+            doc_id
+                .strip_prefix(&dir_prefix)
+                .map(|relative_path| (doc_id.to_string(), relative_path.to_string()))
+        })
+        .collect::<Vec<(String, String)>>();
+    doc_ids.sort();
+    doc_ids
+}
+
+fn path_to_doc_id(
+    path: &Path,
+    docs_roots: &[DocsRoot],
+    ignore_rules: &DocIgnoreRules,
+) -> Option<String> {
+    doc_format_for_path(path)?;
+    if ignore_rules.is_ignored(path, false) {
         return None;
     }
 
-    let relative_path = path.strip_prefix(docs_root).ok()?;
+    let (docs_root, relative_path) = locate_in_docs_roots(path, docs_roots)?;
+    Some(docs_root.scoped_doc_id(&relative_path))
+}
+
+fn locate_in_docs_roots<'a>(
+    path: &Path,
+    docs_roots: &'a [DocsRoot],
+) -> Option<(&'a DocsRoot, String)> {
+    let (docs_root, relative_path) = docs_roots
+        .iter()
+        .filter_map(|docs_root| {
+            path.strip_prefix(&docs_root.path)
+                .ok()
+                .map(|relative_path| (docs_root, relative_path))
+        })
+        .min_by_key(|(_, relative_path)| relative_path.components().count())?;
     if relative_path.as_os_str().is_empty() {
         return None;
     }
 
-    Some(relative_path.to_string_lossy().replace('\\', "/"))
+    Some((
+        docs_root,
+        relative_path.to_string_lossy().replace('\\', "/"),
+    ))
 }
 
 fn now_iso_utc() -> String {
@@ -313,15 +1319,40 @@ fn now_iso_utc() -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{build_docs_changed_payload, DocsChangeKind};
+    use super::{
+        build_docs_changed_payload, seed_content_hashes, ActiveWatcher, DocMove, DocsChangeKind,
+        DocsChangedEventPayload, DocsWatcherRunState, DocsWatcherState, DocsWatcherStatus,
+        UnreadDocChanges, WatchedProject, WatcherMessage, DOCS_ROOT_RETRY_MAX_DELAY,
+    };
+    use crate::doc_content_hash::{DocContentChange, DocContentHash, DocContentHashes};
+    use crate::doc_ignore::DocIgnoreRules;
+    use crate::project_registry::DocsRoot;
+    use crate::project_registry::{WatcherBackend, WatcherSettings};
+    use crate::test_support::{create_temp_workspace, teardown_workspace};
     use notify::event::{CreateKind, DataChange, ModifyKind, RemoveKind, RenameMode};
     use notify::{Event, EventKind};
+    use std::fs;
     use std::path::PathBuf;
+    use std::sync::{mpsc, Arc};
+    use std::time::{Duration, Instant};
 
     fn docs_root() -> PathBuf {
         PathBuf::from("/tmp/coda/docs")
     }
 
+    fn docs_roots() -> Vec<DocsRoot> {
+        vec![
+            DocsRoot {
+                namespace: None,
+                path: docs_root(),
+            },
+            DocsRoot {
+                namespace: Some("adr".to_string()),
+                path: PathBuf::from("/tmp/coda/adr"),
+            },
+        ]
+    }
+
     fn doc(path: &str) -> PathBuf {
         docs_root().join(path)
     }
@@ -330,7 +1361,9 @@ mod tests {
     fn includes_project_id_with_modify_and_create_events() {
         let payload = build_docs_changed_payload(
             "alpha",
-            &docs_root(),
+            &docs_roots(),
+            &DocIgnoreRules::default(),
+            &DocContentHashes::default(),
             &[
                 Ok(
                     Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
@@ -361,7 +1394,9 @@ mod tests {
     fn maps_rename_into_removed_and_changed_doc_ids() {
         let payload = build_docs_changed_payload(
             "alpha",
-            &docs_root(),
+            &docs_roots(),
+            &DocIgnoreRules::default(),
+            &DocContentHashes::default(),
             &[Ok(Event::new(EventKind::Modify(ModifyKind::Name(
                 RenameMode::Both,
             )))
@@ -379,14 +1414,89 @@ mod tests {
             payload.removed_doc_ids,
             vec!["plans/active/old-plan.md".to_string()]
         );
+        assert_eq!(
+            payload.moved,
+            vec![DocMove {
+                from: "plans/active/old-plan.md".to_string(),
+                to: "plans/active/new-plan.md".to_string(),
+            }]
+        );
         assert_eq!(payload.kinds, vec![DocsChangeKind::Renamed]);
     }
 
+    fn known_doc_ids(doc_ids: &[&str]) -> DocContentHashes {
+        let mut known_doc_ids = DocContentHashes::default();
+        for doc_id in doc_ids {
+            known_doc_ids.insert(
+                doc_id.to_string(),
+                DocContentHash::from_contents("---\ntitle: Doc\n---\n", &doc(doc_id)),
+            );
+        }
+        known_doc_ids
+    }
+
+    #[test]
+    fn expands_directory_moves_and_removals_into_known_doc_ids() {
+        let known_doc_ids = known_doc_ids(&[
+            "plans/active/plan.md",
+            "plans/active/nested/step.md",
+            "plans/active-notes.md",
+            "adr:decisions/0001.md",
+        ]);
+        let payload = build_docs_changed_payload(
+            "alpha",
+            &docs_roots(),
+            &DocIgnoreRules::default(),
+            &known_doc_ids,
+            &[
+                Ok(
+                    Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+                        .add_path(doc("plans/active"))
+                        .add_path(doc("plans/completed")),
+                ),
+                Ok(Event::new(EventKind::Remove(RemoveKind::Folder))
+                    .add_path(PathBuf::from("/tmp/coda/adr/decisions"))),
+            ],
+        )
+        .expect("directory events should emit payload");
+
+        assert_eq!(
+            payload.changed_doc_ids,
+            vec![
+                "plans/completed/nested/step.md".to_string(),
+                "plans/completed/plan.md".to_string()
+            ]
+        );
+        assert_eq!(
+            payload.removed_doc_ids,
+            vec![
+                "adr:decisions/0001.md".to_string(),
+                "plans/active/nested/step.md".to_string(),
+                "plans/active/plan.md".to_string()
+            ]
+        );
+        assert_eq!(
+            payload.moved,
+            vec![
+                DocMove {
+                    from: "plans/active/nested/step.md".to_string(),
+                    to: "plans/completed/nested/step.md".to_string(),
+                },
+                DocMove {
+                    from: "plans/active/plan.md".to_string(),
+                    to: "plans/completed/plan.md".to_string(),
+                }
+            ]
+        );
+    }
+
     #[test]
     fn ignores_non_markdown_and_outside_paths() {
         let payload = build_docs_changed_payload(
             "alpha",
-            &docs_root(),
+            &docs_roots(),
+            &DocIgnoreRules::default(),
+            &DocContentHashes::default(),
             &[
                 Ok(
                     Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
@@ -404,7 +1514,9 @@ mod tests {
     fn keeps_doc_id_in_changed_when_remove_and_create_happen_together() {
         let payload = build_docs_changed_payload(
             "beta",
-            &docs_root(),
+            &docs_roots(),
+            &DocIgnoreRules::default(),
+            &DocContentHashes::default(),
             &[
                 Ok(Event::new(EventKind::Remove(RemoveKind::File))
                     .add_path(doc("design-docs/architecture-overview.md"))),
@@ -425,4 +1537,408 @@ mod tests {
             vec![DocsChangeKind::Created, DocsChangeKind::Removed]
         );
     }
+
+    #[test]
+    fn namespaces_doc_ids_and_accepts_registered_formats() {
+        let payload = build_docs_changed_payload(
+            "alpha",
+            &docs_roots(),
+            &DocIgnoreRules::default(),
+            &DocContentHashes::default(),
+            &[Ok(Event::new(EventKind::Modify(ModifyKind::Data(
+                DataChange::Content,
+            )))
+            .add_path(PathBuf::from("/tmp/coda/adr/0001-use-markdown.md"))
+            .add_path(doc("guides/setup.mdx"))
+            .add_path(doc("plans/active/plan.md")))],
+        )
+        .expect("secondary root events should emit payload");
+
+        assert_eq!(
+            payload.changed_doc_ids,
+            vec![
+                "adr:0001-use-markdown.md".to_string(),
+                "guides/setup.mdx".to_string(),
+                "plans/active/plan.md".to_string()
+            ]
+        );
+        assert_eq!(
+            payload.doc_paths(&docs_roots()),
+            vec![
+                PathBuf::from("/tmp/coda/adr/0001-use-markdown.md"),
+                doc("guides/setup.mdx"),
+                doc("plans/active/plan.md")
+            ]
+        );
+    }
+
+    #[test]
+    fn drops_events_for_ignored_doc_paths() {
+        let ignore_rules = DocIgnoreRules::load(
+            &PathBuf::from("/tmp/coda"),
+            &[docs_root()],
+            &["docs/drafts/".to_string(), "*.wip.md".to_string()],
+        );
+        let payload = build_docs_changed_payload(
+            "alpha",
+            &docs_roots(),
+            &ignore_rules,
+            &DocContentHashes::default(),
+            &[Ok(Event::new(EventKind::Modify(ModifyKind::Data(
+                DataChange::Content,
+            )))
+            .add_path(doc("drafts/scratch.md"))
+            .add_path(doc("plans/active/plan.wip.md"))
+            .add_path(doc("plans/active/plan.md")))],
+        )
+        .expect("non-ignored doc should emit payload");
+
+        assert_eq!(
+            payload.changed_doc_ids,
+            vec!["plans/active/plan.md".to_string()]
+        );
+    }
+
+    fn modified_payload(project_id: &str, paths: &[&str]) -> DocsChangedEventPayload {
+        let event = paths.iter().fold(
+            Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content))),
+            |event, path| event.add_path(doc(path)),
+        );
+
+        build_docs_changed_payload(
+            project_id,
+            &docs_roots(),
+            &DocIgnoreRules::default(),
+            &DocContentHashes::default(),
+            &[Ok(event)],
+        )
+        .expect("modify event should emit payload")
+    }
+
+    #[test]
+    fn counts_unread_changes_for_background_projects_only() {
+        let watcher_state = DocsWatcherState::new();
+        watcher_state
+            .inner
+            .lock()
+            .expect("watcher state should lock")
+            .active_project_id = Some("alpha".to_string());
+
+        let mut active_payload = modified_payload("alpha", &["PRD.md"]);
+        watcher_state
+            .record_unread_changes(&mut active_payload)
+            .expect("unread changes should record");
+        assert_eq!(active_payload.unread_change_count, 0);
+
+        let mut first_payload = modified_payload("beta", &["PRD.md", "plans/plan.md"]);
+        let mut second_payload = modified_payload("beta", &["PRD.md"]);
+        for payload in [&mut first_payload, &mut second_payload] {
+            watcher_state
+                .record_unread_changes(payload)
+                .expect("unread changes should record");
+        }
+        assert_eq!(second_payload.unread_change_count, 3);
+        assert_eq!(
+            watcher_state
+                .unread_doc_changes()
+                .expect("unread changes should list"),
+            vec![UnreadDocChanges {
+                project_id: "beta".to_string(),
+                unread_change_count: 3
+            }]
+        );
+
+        watcher_state
+            .mark_doc_changes_read("beta")
+            .expect("changes should mark read");
+        assert!(watcher_state
+            .unread_doc_changes()
+            .expect("unread changes should list")
+            .is_empty());
+    }
+
+    #[test]
+    fn uses_polling_backend_when_native_watching_is_disabled() {
+        let settings = WatcherSettings {
+            backend: WatcherBackend::Poll,
+            poll_interval: Duration::from_millis(250),
+        };
+        let (message_sender, _message_receiver) = mpsc::channel();
+        let watcher =
+            ActiveWatcher::start(settings, message_sender).expect("polling watcher should start");
+        assert_eq!(watcher.backend, WatcherBackend::Poll);
+
+        let watcher_state = DocsWatcherState::new();
+        watcher_state
+            .configure(settings)
+            .expect("watcher settings should apply");
+        assert_eq!(
+            watcher_state.status().expect("status should load"),
+            DocsWatcherStatus {
+                state: DocsWatcherRunState::Stopped,
+                backend: None,
+                configured_backend: WatcherBackend::Poll,
+                poll_interval_ms: 250,
+                project_id: None,
+                last_event_at_iso: None,
+                dropped_event_count: 0,
+                last_error: None,
+                errored_project_ids: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn drops_rewrites_that_leave_doc_content_unchanged() {
+        let workspace_root = create_temp_workspace("docs-watcher");
+        let docs_root = workspace_root.join("docs");
+        let doc_path = docs_root.join("PRD.md");
+        fs::write(&doc_path, "---\ntitle: PRD\n---\n\nBody\n").expect("doc should be written");
+
+        let project = WatchedProject {
+            project_id: "alpha".to_string(),
+            workspace_root: workspace_root.clone(),
+            docs_roots: vec![DocsRoot {
+                namespace: None,
+                path: docs_root.clone(),
+            }],
+            docs_exclude: Vec::new(),
+            ignore_rules: Arc::default(),
+        };
+        let mut content_hashes = seed_content_hashes(&project);
+        let modified_payload = || {
+            build_docs_changed_payload(
+                "alpha",
+                &project.docs_roots,
+                &DocIgnoreRules::default(),
+                &DocContentHashes::default(),
+                &[Ok(Event::new(EventKind::Modify(ModifyKind::Data(
+                    DataChange::Content,
+                )))
+                .add_path(doc_path.clone()))],
+            )
+            .expect("modify event should emit payload")
+        };
+
+        let mut unchanged_payload = modified_payload();
+        assert!(!unchanged_payload.drop_unchanged_content(&project.docs_roots, &mut content_hashes));
+
+        fs::write(&doc_path, "---\ntitle: Renamed\n---\n\nBody\n")
+            .expect("doc should be rewritten");
+        let mut frontmatter_payload = modified_payload();
+        assert!(
+            frontmatter_payload.drop_unchanged_content(&project.docs_roots, &mut content_hashes)
+        );
+        assert_eq!(
+            frontmatter_payload.content_changes,
+            vec![DocContentChange {
+                doc_id: "PRD.md".to_string(),
+                frontmatter_changed: true,
+                body_changed: false,
+            }]
+        );
+
+        teardown_workspace(&workspace_root);
+    }
+
+    #[test]
+    fn rewatches_docs_root_after_it_is_removed_and_recreated() {
+        let workspace_root = create_temp_workspace("docs-watcher-root");
+        let docs_root = workspace_root.join("docs");
+
+        let watcher_state = DocsWatcherState::new();
+        watcher_state
+            .inner
+            .lock()
+            .expect("watcher state should lock")
+            .projects
+            .insert(
+                "alpha".to_string(),
+                WatchedProject {
+                    project_id: "alpha".to_string(),
+                    workspace_root: workspace_root.clone(),
+                    docs_roots: vec![DocsRoot {
+                        namespace: None,
+                        path: docs_root.clone(),
+                    }],
+                    docs_exclude: Vec::new(),
+                    ignore_rules: Arc::default(),
+                },
+            );
+        let settings = WatcherSettings {
+            backend: WatcherBackend::Poll,
+            poll_interval: Duration::from_secs(60),
+        };
+        let (message_sender, _message_receiver) = mpsc::channel();
+        let mut watcher =
+            ActiveWatcher::start(settings, message_sender).expect("polling watcher should start");
+        watcher
+            .sync_watched_paths(&watcher_state)
+            .expect("docs root should be watched");
+        assert!(watcher.watched_paths.contains(&docs_root));
+
+        fs::remove_dir_all(&docs_root).expect("docs root should be removed");
+        let now = Instant::now();
+        assert_eq!(
+            watcher.detect_unavailable_roots(&[], now),
+            vec![docs_root.clone()]
+        );
+        assert!(watcher.watched_paths.is_empty());
+        assert!(watcher.next_root_retry_delay(now).is_some());
+
+        let retry_at = now + DOCS_ROOT_RETRY_MAX_DELAY;
+        assert!(watcher.restore_unavailable_roots(retry_at).is_empty());
+        assert_eq!(watcher.unavailable_roots[&docs_root].attempts, 1);
+
+        fs::create_dir_all(&docs_root).expect("docs root should be recreated");
+        assert_eq!(
+            watcher.restore_unavailable_roots(retry_at + DOCS_ROOT_RETRY_MAX_DELAY),
+            vec![docs_root.clone()]
+        );
+        assert!(watcher.watched_paths.contains(&docs_root));
+        assert_eq!(watcher.next_root_retry_delay(Instant::now()), None);
+
+        teardown_workspace(&workspace_root);
+    }
+
+    #[test]
+    fn reports_dropped_events_and_last_error_in_status() {
+        let watcher_state = DocsWatcherState::new();
+        watcher_state
+            .mark_running(WatcherBackend::Native)
+            .expect("watcher should mark running");
+
+        let dropped = watcher_state
+            .record_events(&[
+                Ok(
+                    Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
+                        .add_path(doc("PRD.md")),
+                ),
+                Err(notify::Error::generic("queue overflow")),
+                Err(notify::Error::generic("inotify limit reached")),
+            ])
+            .expect("events should record");
+        assert!(dropped);
+
+        let status = watcher_state.status().expect("status should load");
+        assert_eq!(status.state, DocsWatcherRunState::Running);
+        assert_eq!(status.backend, Some(WatcherBackend::Native));
+        assert_eq!(status.dropped_event_count, 2);
+        assert_eq!(status.last_error.as_deref(), Some("inotify limit reached"));
+        assert!(status.last_event_at_iso.is_some());
+    }
+
+    #[test]
+    fn records_project_watch_errors_until_the_project_is_unwatched() {
+        let watcher_state = DocsWatcherState::new();
+        watcher_state.record_project_error(
+            "beta",
+            "docs watcher failed: cannot resolve docs root /missing".to_string(),
+        );
+
+        let status = watcher_state.status().expect("status should load");
+        assert_eq!(status.errored_project_ids, vec!["beta".to_string()]);
+        assert_eq!(
+            status.last_error.as_deref(),
+            Some("docs watcher failed: cannot resolve docs root /missing")
+        );
+
+        watcher_state
+            .unwatch_project("beta")
+            .expect("project should unwatch");
+        assert!(watcher_state
+            .status()
+            .expect("status should load")
+            .errored_project_ids
+            .is_empty());
+    }
+
+    #[test]
+    fn reloads_cached_ignore_rules_when_an_ignore_file_changes() {
+        let workspace_root = create_temp_workspace("docs-watcher-ignore");
+        let docs_root = workspace_root.join("docs");
+        let draft_path = docs_root.join("draft.md");
+
+        let watcher_state = DocsWatcherState::new();
+        let mut project = WatchedProject {
+            project_id: "alpha".to_string(),
+            workspace_root: workspace_root.clone(),
+            docs_roots: vec![DocsRoot {
+                namespace: None,
+                path: docs_root.clone(),
+            }],
+            docs_exclude: Vec::new(),
+            ignore_rules: Arc::default(),
+        };
+        project.ignore_rules = Arc::new(project.load_ignore_rules());
+        watcher_state
+            .inner
+            .lock()
+            .expect("watcher state should lock")
+            .projects
+            .insert("alpha".to_string(), project);
+        let cached_rules_ignore_draft = || {
+            watcher_state
+                .watched_projects()
+                .expect("projects should list")[0]
+                .ignore_rules
+                .is_ignored(&draft_path, false)
+        };
+        assert!(!cached_rules_ignore_draft());
+
+        fs::write(docs_root.join(".codaignore"), "draft.md\n")
+            .expect("ignore file should be written");
+        watcher_state
+            .refresh_ignore_rules(&[Ok(Event::new(EventKind::Modify(ModifyKind::Data(
+                DataChange::Content,
+            )))
+            .add_path(doc("PRD.md")))])
+            .expect("ignore rules should refresh");
+        assert!(!cached_rules_ignore_draft());
+
+        watcher_state
+            .refresh_ignore_rules(&[Ok(Event::new(EventKind::Create(CreateKind::File))
+                .add_path(docs_root.join(".codaignore")))])
+            .expect("ignore rules should refresh");
+        assert!(cached_rules_ignore_draft());
+
+        teardown_workspace(&workspace_root);
+    }
+
+    #[test]
+    fn restart_stops_the_previous_thread_and_resets_its_status() {
+        let watcher_state = DocsWatcherState::new();
+        let (message_sender, message_receiver) = mpsc::channel();
+        {
+            let mut runtime = watcher_state
+                .inner
+                .lock()
+                .expect("watcher state should lock");
+            runtime.message_sender = Some(message_sender);
+            runtime.state = DocsWatcherRunState::Errored;
+            runtime.backend = Some(WatcherBackend::Native);
+            runtime.last_error = Some("inotify limit reached".to_string());
+        }
+
+        watcher_state
+            .stop_watcher_thread()
+            .expect("watcher thread should stop");
+
+        assert!(matches!(
+            message_receiver.try_recv(),
+            Ok(WatcherMessage::Stop)
+        ));
+        let runtime = watcher_state
+            .inner
+            .lock()
+            .expect("watcher state should lock");
+        assert_eq!(runtime.thread_generation, 1);
+        assert!(runtime.message_sender.is_none());
+        drop(runtime);
+
+        let status = watcher_state.status().expect("status should load");
+        assert_eq!(status.state, DocsWatcherRunState::Stopped);
+        assert_eq!(status.backend, None);
+        assert_eq!(status.last_error, None);
+    }
 }
@@ -1,11 +1,31 @@
 use serde::Serialize;
 
 mod ask_runtime;
+mod doc_content_hash;
+mod doc_editor;
+mod doc_format;
+mod doc_gardener;
+mod doc_git_status;
+mod doc_history;
+mod doc_ignore;
+mod doc_links;
+mod doc_markdown;
+mod doc_merge;
+mod doc_render;
+mod doc_scan;
+mod doc_summary_cache;
+mod doc_tasks;
+mod doc_templates;
 mod docs_watcher;
+mod plan_lifecycle;
+mod plan_review;
 mod plan_viewer;
+mod project_config_watcher;
 mod project_registration;
 mod project_registry;
 mod project_runtime;
+#[cfg(test)]
+mod test_support;
 
 #[derive(Serialize)]
 struct HealthMessage {
@@ -25,6 +45,8 @@ pub fn run() {
     let project_registry_state = project_runtime::ProjectRegistryState::new()
         .expect("failed to initialize project registry runtime");
     let docs_watcher_state = docs_watcher::DocsWatcherState::new();
+    let doc_git_status_state = doc_git_status::DocGitStatusState::new();
+    let doc_summary_cache_state = doc_summary_cache::DocSummaryCacheState::new();
     let project_registry_state_for_setup = project_registry_state.clone();
     let docs_watcher_state_for_setup = docs_watcher_state.clone();
 
@@ -34,14 +56,38 @@ pub fn run() {
         .manage(ask_runtime_state.clone())
         .manage(project_registry_state.clone())
         .manage(docs_watcher_state.clone())
+        .manage(doc_git_status_state)
+        .manage(doc_summary_cache_state)
         .invoke_handler(tauri::generate_handler![
             get_health_message,
             project_runtime::list_projects,
             project_runtime::get_active_project,
             project_runtime::set_active_project,
             project_runtime::register_project,
+            project_runtime::unregister_project,
             plan_viewer::list_doc_summaries,
             plan_viewer::get_doc_document,
+            plan_viewer::get_doc_section,
+            doc_render::render_doc_html,
+            doc_tasks::toggle_doc_task,
+            doc_editor::create_doc,
+            doc_editor::update_doc_body,
+            doc_editor::update_doc_frontmatter,
+            doc_editor::merge_doc_body,
+            doc_templates::create_doc_from_template,
+            plan_lifecycle::transition_plan,
+            doc_links::list_doc_outgoing_links,
+            doc_links::list_doc_backlinks,
+            doc_links::list_doc_broken_links,
+            doc_gardener::run_doc_gardener,
+            doc_history::list_doc_history,
+            doc_history::get_doc_at_revision,
+            doc_history::diff_doc_revisions,
+            doc_git_status::list_dirty_docs,
+            docs_watcher::get_watcher_status,
+            docs_watcher::restart_docs_watcher,
+            docs_watcher::list_unread_doc_changes,
+            docs_watcher::mark_doc_changes_read,
             ask_runtime::list_pending_ask_sessions,
             ask_runtime::submit_ask_response
         ])
@@ -58,7 +104,17 @@ pub fn run() {
                 &docs_watcher_state_for_setup,
                 &project_registry_state_for_setup,
             )?;
+            project_config_watcher::start_project_config_watcher(
+                app.handle().clone(),
+                &project_registry_state_for_setup,
+                &docs_watcher_state_for_setup,
+            )?;
             ask_runtime::start_ask_socket_server(ask_runtime_state.clone(), app.handle().clone())?;
+            plan_review::resume_plan_reviews(
+                &ask_runtime_state,
+                app.handle(),
+                &project_registry_state_for_setup,
+            );
             Ok(())
         })
         .run(tauri::generate_context!())
@@ -0,0 +1,289 @@
+use crate::ask_runtime::AskRuntimeState;
+use crate::doc_editor::{ensure_doc_revision, read_existing_doc};
+use crate::plan_review::start_plan_review;
+use crate::plan_viewer::{
+    compose_doc_contents, get_doc_document_from_root, is_template_file, resolve_canonical_roots,
+    split_frontmatter, validate_doc_contents, write_doc_file_atomic, DocDocument,
+};
+use crate::project_runtime::ProjectRegistryState;
+use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
+use std::fs;
+use std::path::Path;
+use tauri::{AppHandle, State};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+const PLANS_DIR: &str = "plans";
+const COMPLETED_PLANS_DIR: &str = "plans/completed";
+const LEGACY_ACTIVE_STATUS: &str = "active";
+const STATUS_KEY: &str = "status";
+const TRANSITIONS_KEY: &str = "transitions";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlanState {
+    Draft,
+    Review,
+    Approved,
+    Executing,
+    Completed,
+}
+
+impl PlanState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Review => "review",
+            Self::Approved => "approved",
+            Self::Executing => "executing",
+            Self::Completed => "completed",
+        }
+    }
+
+    fn from_status(status: Option<&str>) -> Option<Self> {
+        match status.map(str::trim) {
+            None | Some("") | Some("draft") => Some(Self::Draft),
+            Some("review") => Some(Self::Review),
+            Some("approved") => Some(Self::Approved),
+            Some("executing") | Some(LEGACY_ACTIVE_STATUS) => Some(Self::Executing),
+            Some("completed") => Some(Self::Completed),
+            Some(_) => None,
+        }
+    }
+
+    pub fn can_transition_to(self, target: Self, manual_close: bool) -> bool {
+        matches!(
+            (self, target),
+            (Self::Draft, Self::Review)
+                | (Self::Review, Self::Approved)
+                | (Self::Review, Self::Draft)
+                | (Self::Approved, Self::Executing)
+                | (Self::Executing, Self::Completed)
+                | (Self::Executing, Self::Review)
+        ) || (manual_close && target == Self::Completed && self != Self::Completed)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlanTransitionRequest<'a> {
+    pub target: PlanState,
+    pub note: Option<&'a str>,
+    pub manual_close: bool,
+    pub at: OffsetDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransitionPlanPayload {
+    doc_id: String,
+    base_revision: String,
+    target: PlanState,
+    note: Option<String>,
+    manual_close: Option<bool>,
+}
+
+#[tauri::command]
+pub fn transition_plan(
+    payload: TransitionPlanPayload,
+    state: State<'_, ProjectRegistryState>,
+    ask_state: State<'_, AskRuntimeState>,
+    app_handle: AppHandle,
+) -> Result<DocDocument, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&payload.doc_id)?;
+    let document = transition_plan_from_root(
+        &active_project.root_path,
+        &docs_root.path,
+        &doc_id,
+        &payload.base_revision,
+        PlanTransitionRequest {
+            target: payload.target,
+            note: payload.note.as_deref(),
+            manual_close: payload.manual_close.unwrap_or(false),
+            at: OffsetDateTime::now_utc(),
+        },
+    )?;
+
+    if payload.target == PlanState::Review {
+        start_plan_review(
+            &ask_state,
+            &app_handle,
+            active_project.root_path.clone(),
+            docs_root.clone(),
+            &document,
+        )
+        .map_err(|error| {
+            format!(
+                "plan review request failed: {} is in review but no review session was opened: {error}",
+                payload.doc_id
+            )
+        })?;
+    }
+
+    Ok(document.scoped(docs_root))
+}
+
+pub fn transition_plan_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    base_revision: &str,
+    request: PlanTransitionRequest<'_>,
+) -> Result<DocDocument, String> {
+    transition_plan_with_fields_from_root(
+        workspace_root,
+        docs_root,
+        doc_id,
+        base_revision,
+        request,
+        |_| Ok(()),
+    )
+}
+
+pub fn transition_plan_with_fields_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    base_revision: &str,
+    request: PlanTransitionRequest<'_>,
+    update_fields: impl FnOnce(&mut Mapping) -> Result<(), String>,
+) -> Result<DocDocument, String> {
+    let doc_id = doc_id.trim();
+    let file_name = ensure_plan_doc_id(doc_id)?;
+
+    let (doc_path, contents) = read_existing_doc(workspace_root, docs_root, doc_id)?;
+    ensure_doc_revision(doc_id, &contents, base_revision)?;
+    let (syntax, frontmatter, body) = split_frontmatter(&contents, &doc_path)?;
+    let mut fields = syntax
+        .parse_mapping(&frontmatter, &doc_path)
+        .map_err(|error| format!("plan transition failed: {error}"))?;
+    update_fields(&mut fields)?;
+
+    let current_status = fields.get(STATUS_KEY).and_then(Value::as_str);
+    let current = PlanState::from_status(current_status).ok_or_else(|| {
+        format!(
+            "plan transition failed: {doc_id} has unknown status '{}'",
+            current_status.unwrap_or_default()
+        )
+    })?;
+
+    if !current.can_transition_to(request.target, request.manual_close) {
+        return Err(format!(
+            "plan transition failed: cannot move {doc_id} from {} to {}",
+            current.as_str(),
+            request.target.as_str()
+        ));
+    }
+
+    append_transition_record(&mut fields, current, &request)?;
+    fields.insert(
+        Value::from(STATUS_KEY),
+        Value::from(request.target.as_str()),
+    );
+
+    let next_doc_id = next_plan_doc_id(doc_id, file_name, request.target);
+    let (_, docs_root_canonical) = resolve_canonical_roots(workspace_root, docs_root)?;
+    let next_doc_path = docs_root_canonical.join(&next_doc_id);
+    if next_doc_id != doc_id && next_doc_path.exists() {
+        return Err(format!(
+            "plan transition failed: {next_doc_id} already exists"
+        ));
+    }
+
+    let next_contents = compose_doc_contents(syntax, &syntax.serialize_mapping(&fields)?, &body);
+    validate_doc_contents(&next_contents, &doc_path)
+        .map_err(|error| format!("plan transition failed: {error}"))?;
+
+    let moved = next_doc_path != doc_path;
+    if moved {
+        if let Some(parent) = next_doc_path.parent() {
+            fs::create_dir_all(parent).map_err(|error| {
+                format!(
+                    "plan transition failed: cannot create directory {}: {error}",
+                    parent.display()
+                )
+            })?;
+        }
+        fs::rename(&doc_path, &next_doc_path).map_err(|error| {
+            format!(
+                "plan transition failed: cannot move plan file {} to {}: {error}",
+                doc_path.display(),
+                next_doc_path.display()
+            )
+        })?;
+    }
+
+    if let Err(error) = write_doc_file_atomic(&next_doc_path, &next_contents) {
+        if moved {
+            if let Err(restore_error) = fs::rename(&next_doc_path, &doc_path) {
+                log::warn!(
+                    "plan transition cannot restore {}: {restore_error}",
+                    doc_path.display()
+                );
+            }
+        }
+        return Err(error);
+    }
+
+    get_doc_document_from_root(workspace_root, docs_root, &next_doc_id)
+}
+
+fn ensure_plan_doc_id(doc_id: &str) -> Result<&str, String> {
+    let file_name = doc_id.rsplit('/').next().unwrap_or(doc_id);
+    let is_plan = doc_id.starts_with(&format!("{PLANS_DIR}/")) && !is_template_file(file_name);
+
+    if !is_plan {
+        return Err(format!(
+            "plan transition failed: {doc_id} is not a plan document"
+        ));
+    }
+
+    Ok(file_name)
+}
+
+fn next_plan_doc_id(doc_id: &str, file_name: &str, target: PlanState) -> String {
+    if target == PlanState::Completed {
+        return format!("{COMPLETED_PLANS_DIR}/{file_name}");
+    }
+
+    doc_id.to_string()
+}
+
+fn append_transition_record(
+    fields: &mut Mapping,
+    from: PlanState,
+    request: &PlanTransitionRequest<'_>,
+) -> Result<(), String> {
+    let at = request
+        .at
+        .format(&Rfc3339)
+        .map_err(|error| format!("plan transition failed: cannot format timestamp: {error}"))?;
+
+    let mut record = Mapping::new();
+    record.insert(Value::from("from"), Value::from(from.as_str()));
+    record.insert(Value::from("to"), Value::from(request.target.as_str()));
+    record.insert(Value::from("at"), Value::from(at));
+    if let Some(note) = request.note.map(str::trim).filter(|note| !note.is_empty()) {
+        record.insert(Value::from("note"), Value::from(note));
+    }
+
+    let transitions = fields
+        .entry(Value::from(TRANSITIONS_KEY))
+        .or_insert_with(|| Value::Sequence(Vec::new()));
+    if transitions.is_null() {
+        *transitions = Value::Sequence(Vec::new());
+    }
+
+    transitions
+        .as_sequence_mut()
+        .ok_or_else(|| {
+            format!("plan transition failed: frontmatter '{TRANSITIONS_KEY}' must be a list")
+        })?
+        .push(Value::Mapping(record));
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "plan_lifecycle_tests.rs"]
+mod tests;
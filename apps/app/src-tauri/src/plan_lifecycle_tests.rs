@@ -0,0 +1,153 @@
+use super::{transition_plan_from_root, PlanState, PlanTransitionRequest};
+use crate::plan_viewer::content_revision;
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
+use std::fs;
+use time::OffsetDateTime;
+
+fn transition_request(target: PlanState, note: Option<&str>) -> PlanTransitionRequest<'_> {
+    PlanTransitionRequest {
+        target,
+        note,
+        manual_close: false,
+        at: OffsetDateTime::from_unix_timestamp(1_771_493_400)
+            .expect("test timestamp should be valid"),
+    }
+}
+
+#[test]
+fn allows_only_documented_plan_transitions() {
+    use PlanState::{Approved, Completed, Draft, Executing, Review};
+
+    let allowed = [
+        (Draft, Review),
+        (Review, Approved),
+        (Review, Draft),
+        (Approved, Executing),
+        (Executing, Completed),
+        (Executing, Review),
+    ];
+    let states = [Draft, Review, Approved, Executing, Completed];
+
+    for from in states {
+        for to in states {
+            assert_eq!(
+                from.can_transition_to(to, false),
+                allowed.contains(&(from, to)),
+                "{from:?} -> {to:?}"
+            );
+        }
+    }
+
+    assert!(Draft.can_transition_to(Completed, true));
+    assert!(Approved.can_transition_to(Completed, true));
+    assert!(!Completed.can_transition_to(Completed, true));
+    assert!(!Draft.can_transition_to(Approved, true));
+}
+
+#[test]
+fn records_transition_history_and_moves_completed_plans() {
+    let workspace = create_temp_workspace("plan-lifecycle");
+    let docs_root = workspace.join("docs");
+    let active_id = "plans/active/2026-02-19-feat-lifecycle-plan.md";
+    let original = "---\ntitle: Lifecycle\nstatus: draft\nowner: ops\n---\n\n## Goal\n";
+    write_doc_file(&docs_root.join(active_id), original);
+
+    let review = transition_plan_from_root(
+        &workspace,
+        &docs_root,
+        active_id,
+        &content_revision(original),
+        transition_request(PlanState::Review, Some("ready for eyes")),
+    )
+    .expect("draft plan should move to review");
+    assert_eq!(review.id, active_id);
+    assert_eq!(review.status.as_deref(), Some("review"));
+
+    let mut revision = review.revision;
+    for target in [PlanState::Approved, PlanState::Executing] {
+        revision = transition_plan_from_root(
+            &workspace,
+            &docs_root,
+            active_id,
+            &revision,
+            transition_request(target, None),
+        )
+        .expect("plan should advance")
+        .revision;
+    }
+
+    let completed = transition_plan_from_root(
+        &workspace,
+        &docs_root,
+        active_id,
+        &revision,
+        transition_request(PlanState::Completed, None),
+    )
+    .expect("executing plan should complete");
+
+    assert_eq!(
+        completed.id,
+        "plans/completed/2026-02-19-feat-lifecycle-plan.md"
+    );
+    assert!(!docs_root.join(active_id).exists());
+
+    let contents =
+        fs::read_to_string(docs_root.join(&completed.id)).expect("completed plan should exist");
+    assert!(contents.contains("status: completed\nowner: ops\n"));
+    assert!(contents.contains(
+        "transitions:\n- from: draft\n  to: review\n  at: 2026-02-19T09:30:00Z\n  note: ready for eyes\n"
+    ));
+    assert_eq!(contents.matches("- from:").count(), 4);
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn rejects_invalid_jumps_and_non_plan_docs() {
+    let workspace = create_temp_workspace("plan-lifecycle");
+    let docs_root = workspace.join("docs");
+    let plan_id = "plans/active/draft-plan.md";
+    let plan = "---\ntitle: Draft\nstatus: draft\n---\n";
+    write_doc_file(&docs_root.join(plan_id), plan);
+    let solution = "---\ntitle: Solution\nstatus: draft\n---\n";
+    write_doc_file(&docs_root.join("solutions/fix.md"), solution);
+
+    let jump_error = transition_plan_from_root(
+        &workspace,
+        &docs_root,
+        plan_id,
+        &content_revision(plan),
+        transition_request(PlanState::Completed, None),
+    )
+    .expect_err("draft plan should not jump to completed");
+    assert!(jump_error.contains("cannot move plans/active/draft-plan.md from draft to completed"));
+    assert_eq!(
+        fs::read_to_string(docs_root.join(plan_id)).expect("plan should be unchanged"),
+        plan
+    );
+
+    let closed = transition_plan_from_root(
+        &workspace,
+        &docs_root,
+        plan_id,
+        &content_revision(plan),
+        PlanTransitionRequest {
+            manual_close: true,
+            ..transition_request(PlanState::Completed, Some("superseded"))
+        },
+    )
+    .expect("manual close should complete any plan");
+    assert_eq!(closed.id, "plans/completed/draft-plan.md");
+
+    let solution_error = transition_plan_from_root(
+        &workspace,
+        &docs_root,
+        "solutions/fix.md",
+        &content_revision(solution),
+        transition_request(PlanState::Review, None),
+    )
+    .expect_err("non-plan docs should be rejected");
+    assert!(solution_error.contains("is not a plan document"));
+
+    teardown_workspace(&workspace);
+}
@@ -0,0 +1,296 @@
+use crate::ask_runtime::{
+    AskAnswer, AskNote, AskOption, AskQuestion, AskRequestBatch, AskResponseBatch, AskRuntimeState,
+};
+use crate::doc_editor::read_existing_doc;
+use crate::plan_lifecycle::{
+    transition_plan_with_fields_from_root, PlanState, PlanTransitionRequest,
+};
+use crate::plan_viewer::{content_revision, load_doc_documents_from_root, DocDocument};
+use crate::project_registry::DocsRoot;
+use crate::project_runtime::ProjectRegistryState;
+use serde_yaml::{Mapping, Value};
+use std::path::{Path, PathBuf};
+use std::thread;
+use tauri::AppHandle;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+const PLAN_REVIEW_QUESTION_ID: &str = "plan_review_decision";
+const PLAN_REVIEW_TIMEOUT_MS: u64 = 0;
+const REVIEW_FEEDBACK_KEY: &str = "review_feedback";
+const PLANS_SECTION: &str = "plans";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanReviewDecision {
+    Approve,
+    Reject,
+    RequestChanges,
+}
+
+impl PlanReviewDecision {
+    const OPTIONS: [Self; 3] = [Self::Approve, Self::Reject, Self::RequestChanges];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Approve => "approve",
+            Self::Reject => "reject",
+            Self::RequestChanges => "request-changes",
+        }
+    }
+
+    fn option(self) -> AskOption {
+        match self {
+            Self::Approve => AskOption::new("Approve", "Move the plan to approved"),
+            Self::Reject => AskOption::new("Reject", "Send the plan back to draft"),
+            Self::RequestChanges => AskOption::new(
+                "Request changes",
+                "Send the plan back to draft with requested changes",
+            ),
+        }
+    }
+
+    fn target_state(self) -> PlanState {
+        match self {
+            Self::Approve => PlanState::Approved,
+            Self::Reject | Self::RequestChanges => PlanState::Draft,
+        }
+    }
+
+    pub fn from_answer(answer: &AskAnswer) -> Option<Self> {
+        if answer.id() != PLAN_REVIEW_QUESTION_ID {
+            return None;
+        }
+
+        if answer.other_text().is_some() {
+            return Some(Self::RequestChanges);
+        }
+
+        answer
+            .selected_index()
+            .and_then(|index| Self::OPTIONS.get(index).copied())
+    }
+}
+
+pub fn build_plan_review_request(document: &DocDocument) -> AskRequestBatch {
+    AskRequestBatch::new(
+        vec![AskQuestion::new(
+            "Plan review",
+            PLAN_REVIEW_QUESTION_ID,
+            &format!("Review plan: {}", document.display_title),
+            PlanReviewDecision::OPTIONS
+                .into_iter()
+                .map(PlanReviewDecision::option)
+                .collect(),
+        )],
+        Some(AskNote::new("Review feedback", false)),
+    )
+}
+
+pub fn resume_plan_reviews(
+    ask_state: &AskRuntimeState,
+    app_handle: &AppHandle,
+    registry_state: &ProjectRegistryState,
+) {
+    let active_project = match registry_state.active_project_context() {
+        Ok(active_project) => active_project,
+        Err(error) => {
+            log::warn!("plan reviews were not resumed: {error}");
+            return;
+        }
+    };
+
+    for docs_root in &active_project.docs_roots {
+        let documents = match list_plans_in_review_from_root(
+            &active_project.root_path,
+            &docs_root.path,
+            &active_project.docs_exclude,
+        ) {
+            Ok(documents) => documents,
+            Err(error) => {
+                log::warn!("plan reviews were not resumed: {error}");
+                continue;
+            }
+        };
+
+        for document in documents {
+            if let Err(error) = start_plan_review(
+                ask_state,
+                app_handle,
+                active_project.root_path.clone(),
+                docs_root.clone(),
+                &document,
+            ) {
+                log::warn!("failed to resume review for {}: {error}", document.id);
+            }
+        }
+    }
+}
+
+pub fn start_plan_review(
+    ask_state: &AskRuntimeState,
+    app_handle: &AppHandle,
+    workspace_root: PathBuf,
+    docs_root: DocsRoot,
+    document: &DocDocument,
+) -> Result<(), String> {
+    let ask_id = plan_review_ask_id(&docs_root.path, document);
+    let response_receiver = ask_state.open_app_session(
+        app_handle,
+        ask_id.clone(),
+        Some(docs_root.scoped_doc_id(&document.id)),
+        build_plan_review_request(document),
+        PLAN_REVIEW_TIMEOUT_MS,
+    )?;
+
+    let doc_id = document.id.clone();
+    thread::Builder::new()
+        .name("coda-plan-review".to_string())
+        .spawn(move || {
+            let Ok(response) = response_receiver.recv() else {
+                log::warn!("plan review session {ask_id} closed without a response");
+                return;
+            };
+
+            if let Err(error) =
+                apply_plan_review_response(&workspace_root, &docs_root.path, &doc_id, &response)
+            {
+                log::warn!("plan review for {doc_id} was not applied: {error}");
+            }
+        })
+        .map(|_| ())
+        .map_err(|error| format!("failed to start plan review thread: {error}"))
+}
+
+fn plan_review_ask_id(docs_root: &Path, document: &DocDocument) -> String {
+    let doc_path = docs_root.join(&document.id);
+    format!(
+        "plan-review-{}",
+        content_revision(&format!(
+            "{}\n{}",
+            doc_path.to_string_lossy(),
+            document.revision
+        ))
+    )
+}
+
+fn apply_plan_review_response(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    response: &AskResponseBatch,
+) -> Result<Option<DocDocument>, String> {
+    if !response.is_answered() {
+        log::info!("plan review for {doc_id} ended without a decision");
+        return Ok(None);
+    }
+
+    let answer = response
+        .answers()
+        .first()
+        .ok_or_else(|| "plan review response has no answer".to_string())?;
+    let decision = PlanReviewDecision::from_answer(answer)
+        .ok_or_else(|| "plan review response has an unknown decision".to_string())?;
+    let note = [answer.other_text(), response.note()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<&str>>()
+        .join("\n\n");
+
+    apply_plan_review_decision_from_root(
+        workspace_root,
+        docs_root,
+        doc_id,
+        decision,
+        Some(note.as_str()).filter(|note| !note.is_empty()),
+        OffsetDateTime::now_utc(),
+    )
+    .map(Some)
+}
+
+pub fn apply_plan_review_decision_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    decision: PlanReviewDecision,
+    note: Option<&str>,
+    at: OffsetDateTime,
+) -> Result<DocDocument, String> {
+    let (_, contents) = read_existing_doc(workspace_root, docs_root, doc_id)?;
+
+    transition_plan_with_fields_from_root(
+        workspace_root,
+        docs_root,
+        doc_id,
+        &content_revision(&contents),
+        PlanTransitionRequest {
+            target: decision.target_state(),
+            note,
+            manual_close: false,
+            at,
+        },
+        |fields| append_review_feedback(fields, doc_id, decision, note, at),
+    )
+}
+
+pub fn list_plans_in_review_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    docs_exclude: &[String],
+) -> Result<Vec<DocDocument>, String> {
+    Ok(
+        load_doc_documents_from_root(workspace_root, docs_root, docs_exclude)?
+            .into_iter()
+            .filter(|document| {
+                document.section == PLANS_SECTION
+                    && !document.is_template
+                    && document.status.as_deref() == Some(PlanState::Review.as_str())
+            })
+            .collect(),
+    )
+}
+
+fn append_review_feedback(
+    fields: &mut Mapping,
+    doc_id: &str,
+    decision: PlanReviewDecision,
+    note: Option<&str>,
+    at: OffsetDateTime,
+) -> Result<(), String> {
+    if fields.get("status").and_then(Value::as_str) != Some(PlanState::Review.as_str()) {
+        return Err(format!(
+            "plan review failed: {doc_id} is no longer in review"
+        ));
+    }
+
+    let mut entry = Mapping::new();
+    entry.insert(Value::from("decision"), Value::from(decision.as_str()));
+    entry.insert(
+        Value::from("at"),
+        Value::from(
+            at.format(&Rfc3339)
+                .map_err(|error| format!("plan review failed: cannot format timestamp: {error}"))?,
+        ),
+    );
+    if let Some(note) = note {
+        entry.insert(Value::from("note"), Value::from(note));
+    }
+
+    let feedback = fields
+        .entry(Value::from(REVIEW_FEEDBACK_KEY))
+        .or_insert_with(|| Value::Sequence(Vec::new()));
+    if feedback.is_null() {
+        *feedback = Value::Sequence(Vec::new());
+    }
+    feedback
+        .as_sequence_mut()
+        .ok_or_else(|| {
+            format!("plan review failed: frontmatter '{REVIEW_FEEDBACK_KEY}' must be a list")
+        })?
+        .push(Value::Mapping(entry));
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "plan_review_tests.rs"]
+mod tests;
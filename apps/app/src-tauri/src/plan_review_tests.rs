@@ -0,0 +1,211 @@
+use super::{
+    apply_plan_review_decision_from_root, build_plan_review_request,
+    list_plans_in_review_from_root, plan_review_ask_id, PlanReviewDecision,
+};
+use crate::ask_runtime::AskAnswer;
+use crate::plan_viewer::get_doc_document_from_root;
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
+use std::fs;
+use time::OffsetDateTime;
+
+fn review_time() -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp(1_771_493_400).expect("test timestamp should be valid")
+}
+
+fn answer(value: serde_json::Value) -> AskAnswer {
+    serde_json::from_value(value).expect("test answer should deserialize")
+}
+
+const REVIEW_PLAN_ID: &str = "plans/active/2026-02-19-feat-review-plan.md";
+const REVIEW_PLAN: &str = "---\ntitle: Review Me\nstatus: review\n---\n\n## Goal\n";
+
+#[test]
+fn builds_review_question_and_maps_answers_to_decisions() {
+    let workspace = create_temp_workspace("plan-review");
+    let docs_root = workspace.join("docs");
+    write_doc_file(&docs_root.join(REVIEW_PLAN_ID), REVIEW_PLAN);
+    let document = get_doc_document_from_root(&workspace, &docs_root, REVIEW_PLAN_ID)
+        .expect("plan should load");
+
+    let request = serde_json::to_value(build_plan_review_request(&document))
+        .expect("request should serialize");
+    assert_eq!(
+        request["questions"][0]["question"],
+        "Review plan: Review Me"
+    );
+    let labels = request["questions"][0]["options"]
+        .as_array()
+        .expect("options should be a list")
+        .iter()
+        .map(|option| option["label"].as_str().unwrap_or_default())
+        .collect::<Vec<&str>>();
+    assert_eq!(labels, vec!["Approve", "Reject", "Request changes"]);
+
+    let selected = |index: usize| {
+        answer(serde_json::json!({
+            "id": "plan_review_decision",
+            "selected_label": "",
+            "selected_index": index,
+            "used_other": false,
+            "other_text": null
+        }))
+    };
+    assert_eq!(
+        PlanReviewDecision::from_answer(&selected(0)),
+        Some(PlanReviewDecision::Approve)
+    );
+    assert_eq!(
+        PlanReviewDecision::from_answer(&selected(2)),
+        Some(PlanReviewDecision::RequestChanges)
+    );
+    assert_eq!(PlanReviewDecision::from_answer(&selected(3)), None);
+    assert_eq!(
+        PlanReviewDecision::from_answer(&answer(serde_json::json!({
+            "id": "plan_review_decision",
+            "selected_label": "Other",
+            "selected_index": null,
+            "used_other": true,
+            "other_text": "split step 2"
+        }))),
+        Some(PlanReviewDecision::RequestChanges)
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn approval_moves_plan_forward_and_stores_feedback() {
+    let workspace = create_temp_workspace("plan-review");
+    let docs_root = workspace.join("docs");
+    write_doc_file(&docs_root.join(REVIEW_PLAN_ID), REVIEW_PLAN);
+
+    let document = apply_plan_review_decision_from_root(
+        &workspace,
+        &docs_root,
+        REVIEW_PLAN_ID,
+        PlanReviewDecision::Approve,
+        Some("looks good"),
+        review_time(),
+    )
+    .expect("approval should apply");
+
+    assert_eq!(document.status.as_deref(), Some("approved"));
+    let contents =
+        fs::read_to_string(docs_root.join(REVIEW_PLAN_ID)).expect("plan should be readable");
+    assert!(contents.contains(
+        "review_feedback:\n- decision: approve\n  at: 2026-02-19T09:30:00Z\n  note: looks good\n"
+    ));
+    assert!(contents.contains("- from: review\n  to: approved\n"));
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn change_requests_return_plan_to_draft_only_while_in_review() {
+    let workspace = create_temp_workspace("plan-review");
+    let docs_root = workspace.join("docs");
+    write_doc_file(&docs_root.join(REVIEW_PLAN_ID), REVIEW_PLAN);
+
+    let document = apply_plan_review_decision_from_root(
+        &workspace,
+        &docs_root,
+        REVIEW_PLAN_ID,
+        PlanReviewDecision::RequestChanges,
+        Some("split step 2"),
+        review_time(),
+    )
+    .expect("change request should apply");
+    assert_eq!(document.status.as_deref(), Some("draft"));
+
+    let error = apply_plan_review_decision_from_root(
+        &workspace,
+        &docs_root,
+        REVIEW_PLAN_ID,
+        PlanReviewDecision::Approve,
+        None,
+        review_time(),
+    )
+    .expect_err("stale review answers should not apply");
+    assert!(error.contains("is no longer in review"));
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn leaves_plan_untouched_when_the_decision_cannot_be_applied() {
+    let workspace = create_temp_workspace("plan-review");
+    let docs_root = workspace.join("docs");
+    let plan = "---\ntitle: Review Me\nstatus: review\ntransitions: broken\n---\n\n## Goal\n";
+    write_doc_file(&docs_root.join(REVIEW_PLAN_ID), plan);
+
+    let error = apply_plan_review_decision_from_root(
+        &workspace,
+        &docs_root,
+        REVIEW_PLAN_ID,
+        PlanReviewDecision::Reject,
+        Some("not yet"),
+        review_time(),
+    )
+    .expect_err("invalid transitions should fail");
+
+    assert!(error.contains("'transitions' must be a list"));
+    assert_eq!(
+        fs::read_to_string(docs_root.join(REVIEW_PLAN_ID)).expect("plan should be readable"),
+        plan
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn lists_plans_waiting_for_review_so_sessions_can_resume() {
+    let workspace = create_temp_workspace("plan-review");
+    let docs_root = workspace.join("docs");
+    write_doc_file(&docs_root.join(REVIEW_PLAN_ID), REVIEW_PLAN);
+    write_doc_file(
+        &docs_root.join("plans/active/2026-02-19-feat-draft-plan.md"),
+        "---\ntitle: Draft\nstatus: draft\n---\n\n## Goal\n",
+    );
+    write_doc_file(&docs_root.join("plans/.template.md"), REVIEW_PLAN);
+    write_doc_file(&docs_root.join("solutions/review.md"), REVIEW_PLAN);
+
+    let plans = list_plans_in_review_from_root(&workspace, &docs_root, &[])
+        .expect("plans in review should list");
+
+    assert_eq!(
+        plans
+            .iter()
+            .map(|document| document.id.as_str())
+            .collect::<Vec<&str>>(),
+        vec![REVIEW_PLAN_ID]
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn derives_a_new_ask_id_for_each_plan_revision() {
+    let workspace = create_temp_workspace("plan-review");
+    let docs_root = workspace.join("docs");
+    write_doc_file(&docs_root.join(REVIEW_PLAN_ID), REVIEW_PLAN);
+    let first_review = get_doc_document_from_root(&workspace, &docs_root, REVIEW_PLAN_ID)
+        .expect("plan should load");
+
+    write_doc_file(
+        &docs_root.join(REVIEW_PLAN_ID),
+        &format!("{REVIEW_PLAN}\nResubmitted with changes.\n"),
+    );
+    let second_review = get_doc_document_from_root(&workspace, &docs_root, REVIEW_PLAN_ID)
+        .expect("plan should load");
+
+    assert_eq!(
+        plan_review_ask_id(&docs_root, &first_review),
+        plan_review_ask_id(&docs_root, &first_review)
+    );
+    assert_ne!(
+        plan_review_ask_id(&docs_root, &first_review),
+        plan_review_ask_id(&docs_root, &second_review)
+    );
+
+    teardown_workspace(&workspace);
+}
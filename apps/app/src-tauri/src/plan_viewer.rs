@@ -1,11 +1,26 @@
+use crate::doc_format::{
+    doc_format_for_path, has_doc_extension, strip_doc_extension, supported_doc_extensions,
+    BodySyntax, FrontmatterSyntax,
+};
+use crate::doc_git_status::{DocGitStatus, DocGitStatusState};
+use crate::doc_ignore::DocIgnoreRules;
+use crate::doc_markdown::{
+    extract_heading_outline, extract_task_items, heading_section, MarkdownHeading, MarkdownTask,
+};
+use crate::doc_scan::{
+    collect_doc_paths, never_cancelled, parallel_map, scan_worker_count, DocScanCancellation,
+};
+use crate::doc_summary_cache::{DocSummaryCache, DocSummaryCacheState};
+use crate::project_registry::DocsRoot;
 use crate::project_runtime::ProjectRegistryState;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 use tauri::State;
 
-const DOC_FILE_EXTENSION: &str = "md";
-const FRONTMATTER_DELIMITER: &str = "---";
+const REVISION_HASH_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const REVISION_HASH_PRIME: u64 = 0x0000_0100_0000_01b3;
 
 #[derive(Debug, Deserialize)]
 struct DocFrontmatter {
@@ -14,6 +29,17 @@ struct DocFrontmatter {
     status: Option<String>,
     tags: Option<Vec<String>>,
     milestone: Option<String>,
+    related: Option<RelatedDocRefs>,
+    related_plan: Option<RelatedDocRefs>,
+    related_plans: Option<RelatedDocRefs>,
+    related_solutions: Option<RelatedDocRefs>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RelatedDocRefs {
+    One(String),
+    Many(Vec<String>),
 }
 
 #[derive(Debug)]
@@ -23,6 +49,7 @@ struct ParsedMetadata {
     status: Option<String>,
     tags: Vec<String>,
     milestone: Option<String>,
+    related: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -39,40 +66,101 @@ pub struct DocSummary {
     status: Option<String>,
     tags: Vec<String>,
     milestone: Option<String>,
+    tasks_total: usize,
+    tasks_done: usize,
     is_template: bool,
     is_hidden: bool,
+    git_status: Option<DocGitStatus>,
 }
 
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DocDocument {
-    id: String,
-    file_name: String,
-    doc_path: String,
-    relative_path: String,
-    section: String,
-    title: Option<String>,
-    display_title: String,
-    date: Option<String>,
-    status: Option<String>,
-    tags: Vec<String>,
-    milestone: Option<String>,
-    is_template: bool,
-    is_hidden: bool,
-    markdown_body: String,
+    pub id: String,
+    pub revision: String,
+    pub file_name: String,
+    pub doc_path: String,
+    pub relative_path: String,
+    pub section: String,
+    pub title: Option<String>,
+    pub display_title: String,
+    pub date: Option<String>,
+    pub status: Option<String>,
+    pub tags: Vec<String>,
+    pub milestone: Option<String>,
+    pub related: Vec<String>,
+    pub tasks_total: usize,
+    pub tasks_done: usize,
+    pub tasks: Vec<MarkdownTask>,
+    pub is_template: bool,
+    pub is_hidden: bool,
+    pub markdown_body: String,
+    pub outline: Vec<MarkdownHeading>,
+    #[serde(skip)]
+    pub body_line_offset: usize,
+}
+
+#[derive(Debug)]
+struct DocPathScan {
+    workspace_root: PathBuf,
+    docs_root: PathBuf,
+    doc_paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DocSection {
+    doc_id: String,
+    heading: MarkdownHeading,
+    markdown: String,
 }
 
 #[tauri::command]
 pub fn list_doc_summaries(
     state: State<'_, ProjectRegistryState>,
+    git_status_state: State<'_, DocGitStatusState>,
+    summary_cache_state: State<'_, DocSummaryCacheState>,
     include_hidden: Option<bool>,
 ) -> Result<Vec<DocSummary>, String> {
     let active_project = state.active_project_context()?;
-    list_doc_summaries_from_root(
-        &active_project.root_path,
-        &active_project.docs_path,
-        include_hidden.unwrap_or(false),
-    )
+    let registry_state: &ProjectRegistryState = &state;
+    let is_cancelled = || !registry_state.is_active_project(&active_project.project_id);
+    let mut summaries = Vec::new();
+
+    for docs_root in &active_project.docs_roots {
+        let mut root_summaries = summary_cache_state.with_docs_root_cache(
+            &active_project.project_id,
+            &docs_root.path,
+            |cache| {
+                list_doc_summaries_from_root(
+                    &active_project.root_path,
+                    &docs_root.path,
+                    &active_project.docs_exclude,
+                    include_hidden.unwrap_or(false),
+                    cache,
+                    &is_cancelled,
+                )
+            },
+        )?;
+
+        match git_status_state.snapshot(&active_project.root_path, &docs_root.path) {
+            Ok(snapshot) => {
+                for summary in &mut root_summaries {
+                    summary.git_status = Some(snapshot.status_for(&summary.id));
+                }
+            }
+            Err(error) => log::debug!("doc summaries listed without git status: {error}"),
+        }
+
+        summaries.extend(
+            root_summaries
+                .into_iter()
+                .map(|summary| summary.scoped(docs_root)),
+        );
+    }
+
+    sort_doc_summaries(&mut summaries);
+    Ok(summaries)
 }
 
 #[tauri::command]
@@ -81,65 +169,97 @@ pub fn get_doc_document(
     state: State<'_, ProjectRegistryState>,
 ) -> Result<DocDocument, String> {
     let active_project = state.active_project_context()?;
-    get_doc_document_from_root(
-        &active_project.root_path,
-        &active_project.docs_path,
-        &doc_id,
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&doc_id)?;
+    get_doc_document_from_root(&active_project.root_path, &docs_root.path, &doc_id)
+        .map(|document| document.scoped(docs_root))
+}
+
+#[tauri::command]
+pub fn get_doc_section(
+    doc_id: String,
+    slug: String,
+    state: State<'_, ProjectRegistryState>,
+) -> Result<DocSection, String> {
+    let active_project = state.active_project_context()?;
+    let (docs_root, doc_id) = active_project.resolve_docs_root(&doc_id)?;
+    get_doc_section_from_root(&active_project.root_path, &docs_root.path, &doc_id, &slug).map(
+        |section| DocSection {
+            doc_id: docs_root.scoped_doc_id(&section.doc_id),
+            ..section
+        },
     )
 }
 
-fn list_doc_summaries_from_root(
+pub fn list_doc_summaries_from_root(
     workspace_root: &Path,
     docs_root: &Path,
+    docs_exclude: &[String],
     include_hidden: bool,
+    cache: &mut DocSummaryCache,
+    is_cancelled: DocScanCancellation<'_>,
 ) -> Result<Vec<DocSummary>, String> {
     if !docs_root.exists() {
         return Ok(Vec::new());
     }
 
-    let docs_root_canonical = docs_root.canonicalize().map_err(|error| {
-        format!(
-            "failed to resolve docs directory {}: {error}",
-            docs_root.display()
-        )
-    })?;
-    let workspace_root_canonical = workspace_root.canonicalize().map_err(|error| {
-        format!(
-            "failed to resolve workspace root {}: {error}",
-            workspace_root.display()
-        )
-    })?;
+    let started_at = Instant::now();
+    let scan = scan_doc_paths(workspace_root, docs_root, docs_exclude, is_cancelled)?;
+    let walked_at = Instant::now();
 
-    let markdown_paths = collect_markdown_paths(docs_root)?;
     let mut summaries = Vec::new();
-
-    for path in markdown_paths {
-        let path_canonical = path.canonicalize().map_err(|error| {
+    let mut uncached_docs = Vec::new();
+    for doc_path in &scan.doc_paths {
+        let metadata = fs::metadata(doc_path).map_err(|error| {
             format!(
-                "failed to resolve document path {}: {error}",
-                path.display()
+                "failed to read document metadata {}: {error}",
+                doc_path.display()
             )
         })?;
 
-        if !path_canonical.starts_with(&docs_root_canonical) {
-            return Err(format!(
-                "document path {} is outside allowed docs directory {}",
-                path.display(),
-                docs_root.display()
-            ));
+        match cache.get(doc_path, &metadata) {
+            Some(summary) => summaries.push(summary),
+            None => uncached_docs.push((doc_path, metadata)),
         }
+    }
 
-        let document = parse_doc_document(
-            &path_canonical,
-            &workspace_root_canonical,
-            &docs_root_canonical,
-        )?;
+    let parsed_summaries = parallel_map(&uncached_docs, is_cancelled, |(doc_path, _)| {
+        parse_doc_document(doc_path, &scan.workspace_root, &scan.docs_root).map(DocSummary::from)
+    })?;
+    for ((doc_path, metadata), summary) in uncached_docs.iter().zip(parsed_summaries) {
+        cache.insert(doc_path.to_path_buf(), metadata, summary.clone());
+        summaries.push(summary);
+    }
 
-        if !include_hidden && (document.is_hidden || document.is_template) {
-            continue;
-        }
+    let stats = cache.finish_scan(&scan.doc_paths);
+    log::info!(
+        "listed {} doc summaries under {} in {:?} (walk {:?}, parse {:?}, {} cached, {} parsed, {} workers)",
+        scan.doc_paths.len(),
+        scan.docs_root.display(),
+        started_at.elapsed(),
+        walked_at.duration_since(started_at),
+        walked_at.elapsed(),
+        stats.cached,
+        stats.parsed,
+        scan_worker_count()
+    );
+
+    summaries.retain(|summary| include_hidden || !(summary.is_hidden || summary.is_template));
+    sort_doc_summaries(&mut summaries);
+    Ok(summaries)
+}
 
-        summaries.push(DocSummary {
+fn sort_doc_summaries(summaries: &mut [DocSummary]) {
+    summaries.sort_by(|left, right| {
+        left.section
+            .cmp(&right.section)
+            .then_with(|| right.date.cmp(&left.date))
+            .then_with(|| left.doc_path.cmp(&right.doc_path))
+    });
+}
+
+impl From<DocDocument> for DocSummary {
+    fn from(document: DocDocument) -> Self {
+        Self {
             id: document.id,
             file_name: document.file_name,
             doc_path: document.doc_path,
@@ -151,94 +271,216 @@ fn list_doc_summaries_from_root(
             status: document.status,
             tags: document.tags,
             milestone: document.milestone,
+            tasks_total: document.tasks_total,
+            tasks_done: document.tasks_done,
             is_template: document.is_template,
             is_hidden: document.is_hidden,
-        });
+            git_status: None,
+        }
     }
+}
 
-    summaries.sort_by(|left, right| {
-        left.section
-            .cmp(&right.section)
-            .then_with(|| right.date.cmp(&left.date))
-            .then_with(|| left.doc_path.cmp(&right.doc_path))
-    });
+impl DocSummary {
+    fn scoped(self, docs_root: &DocsRoot) -> Self {
+        Self {
+            id: docs_root.scoped_doc_id(&self.id),
+            section: docs_root.scoped_section(&self.section),
+            ..self
+        }
+    }
+}
 
-    Ok(summaries)
+impl DocDocument {
+    pub fn scoped(self, docs_root: &DocsRoot) -> Self {
+        Self {
+            id: docs_root.scoped_doc_id(&self.id),
+            section: docs_root.scoped_section(&self.section),
+            ..self
+        }
+    }
 }
 
-fn collect_markdown_paths(root: &Path) -> Result<Vec<PathBuf>, String> {
-    let mut paths = Vec::new();
-    collect_markdown_paths_recursive(root, &mut paths)?;
-    Ok(paths)
+pub fn load_doc_documents_from_roots(
+    workspace_root: &Path,
+    docs_roots: &[DocsRoot],
+    docs_exclude: &[String],
+) -> Result<Vec<DocDocument>, String> {
+    let mut documents = Vec::new();
+
+    for docs_root in docs_roots {
+        documents.extend(
+            load_doc_documents_from_root(workspace_root, &docs_root.path, docs_exclude)?
+                .into_iter()
+                .map(|document| document.scoped(docs_root)),
+        );
+    }
+
+    Ok(documents)
+}
+
+pub fn load_doc_documents_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    docs_exclude: &[String],
+) -> Result<Vec<DocDocument>, String> {
+    if !docs_root.exists() {
+        return Ok(Vec::new());
+    }
+
+    let scan = scan_doc_paths(workspace_root, docs_root, docs_exclude, &never_cancelled)?;
+    parallel_map(&scan.doc_paths, &never_cancelled, |doc_path| {
+        parse_doc_document(doc_path, &scan.workspace_root, &scan.docs_root)
+    })
 }
 
-fn collect_markdown_paths_recursive(root: &Path, paths: &mut Vec<PathBuf>) -> Result<(), String> {
-    let entries = fs::read_dir(root)
-        .map_err(|error| format!("failed to read directory {}: {error}", root.display()))?;
+fn scan_doc_paths(
+    workspace_root: &Path,
+    docs_root: &Path,
+    docs_exclude: &[String],
+    is_cancelled: DocScanCancellation<'_>,
+) -> Result<DocPathScan, String> {
+    let (workspace_root_canonical, docs_root_canonical) =
+        resolve_canonical_roots(workspace_root, docs_root)?;
 
-    for entry in entries {
-        let entry = entry.map_err(|error| {
+    let ignore_rules = DocIgnoreRules::load(
+        &workspace_root_canonical,
+        std::slice::from_ref(&docs_root_canonical),
+        docs_exclude,
+    );
+    let markdown_paths = collect_doc_paths(&docs_root_canonical, &ignore_rules, is_cancelled)?;
+    let doc_paths = parallel_map(&markdown_paths, is_cancelled, |path| {
+        let path_canonical = path.canonicalize().map_err(|error| {
             format!(
-                "failed to read directory entry in {}: {error}",
-                root.display()
+                "failed to resolve document path {}: {error}",
+                path.display()
             )
         })?;
-        let entry_path = entry.path();
 
-        if entry_path.is_dir() {
-            collect_markdown_paths_recursive(&entry_path, paths)?;
-            continue;
+        if !path_canonical.starts_with(&docs_root_canonical) {
+            return Err(format!(
+                "document path {} is outside allowed docs directory {}",
+                path.display(),
+                docs_root.display()
+            ));
         }
 
-        if is_markdown_file(&entry_path) {
-            paths.push(entry_path);
-        }
-    }
+        Ok(path_canonical)
+    })?;
 
-    Ok(())
+    Ok(DocPathScan {
+        workspace_root: workspace_root_canonical,
+        docs_root: docs_root_canonical,
+        doc_paths,
+    })
 }
 
-fn get_doc_document_from_root(
+pub fn resolve_canonical_roots(
     workspace_root: &Path,
     docs_root: &Path,
-    doc_id: &str,
-) -> Result<DocDocument, String> {
-    validate_doc_id(doc_id)?;
-
-    let docs_root_canonical = docs_root.canonicalize().map_err(|error| {
-        format!(
-            "failed to resolve docs directory {}: {error}",
-            docs_root.display()
-        )
-    })?;
+) -> Result<(PathBuf, PathBuf), String> {
     let workspace_root_canonical = workspace_root.canonicalize().map_err(|error| {
         format!(
             "failed to resolve workspace root {}: {error}",
             workspace_root.display()
         )
     })?;
+    let docs_root_canonical = docs_root.canonicalize().map_err(|error| {
+        format!(
+            "failed to resolve docs directory {}: {error}",
+            docs_root.display()
+        )
+    })?;
+
+    Ok((workspace_root_canonical, docs_root_canonical))
+}
+
+pub fn get_doc_document_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+) -> Result<DocDocument, String> {
+    let (workspace_root_canonical, docs_root_canonical) =
+        resolve_canonical_roots(workspace_root, docs_root)?;
+    let canonical_doc_path = resolve_existing_doc_path(&docs_root_canonical, doc_id)?;
+
+    parse_doc_document(
+        &canonical_doc_path,
+        &workspace_root_canonical,
+        &docs_root_canonical,
+    )
+}
+
+pub fn resolve_existing_doc_path(
+    docs_root_canonical: &Path,
+    doc_id: &str,
+) -> Result<PathBuf, String> {
+    validate_doc_id(doc_id)?;
 
     let requested_path = docs_root_canonical.join(doc_id);
     let canonical_doc_path = requested_path
         .canonicalize()
         .map_err(|error| format!("failed to locate document {doc_id}: {error}"))?;
 
-    if !canonical_doc_path.starts_with(&docs_root_canonical) {
+    if !canonical_doc_path.starts_with(docs_root_canonical) {
         return Err("document path is outside allowed docs directory".to_string());
     }
 
-    if !is_markdown_file(&canonical_doc_path) {
-        return Err(format!("document must be a .{DOC_FILE_EXTENSION} file"));
+    if !is_doc_file(&canonical_doc_path) {
+        return Err(format!(
+            "document must be a supported document file ({})",
+            supported_doc_extensions()
+        ));
     }
 
-    parse_doc_document(
-        &canonical_doc_path,
-        &workspace_root_canonical,
-        &docs_root_canonical,
-    )
+    Ok(canonical_doc_path)
+}
+
+pub fn write_doc_file_atomic(path: &Path, contents: &str) -> Result<(), String> {
+    let temp_file_name = format!(
+        ".{}.tmp",
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("document.md")
+    );
+    let temporary_path = path.with_file_name(temp_file_name);
+
+    fs::write(&temporary_path, contents).map_err(|error| {
+        format!(
+            "failed to write document temporary file {}: {error}",
+            temporary_path.display()
+        )
+    })?;
+
+    fs::rename(&temporary_path, path).map_err(|error| {
+        let _ = fs::remove_file(&temporary_path);
+        format!(
+            "failed to atomically replace document {}: {error}",
+            path.display()
+        )
+    })
 }
 
-fn validate_doc_id(doc_id: &str) -> Result<(), String> {
+fn get_doc_section_from_root(
+    workspace_root: &Path,
+    docs_root: &Path,
+    doc_id: &str,
+    slug: &str,
+) -> Result<DocSection, String> {
+    let document = get_doc_document_from_root(workspace_root, docs_root, doc_id)?;
+    let normalized_slug = slug.trim().trim_start_matches('#').to_lowercase();
+
+    let (heading, range) =
+        heading_section(&document.markdown_body, &document.outline, &normalized_slug)
+            .ok_or_else(|| format!("section '{normalized_slug}' not found in document {doc_id}"))?;
+
+    Ok(DocSection {
+        doc_id: document.id.clone(),
+        heading: heading.clone(),
+        markdown: document.markdown_body[range].to_string(),
+    })
+}
+
+pub fn validate_doc_id(doc_id: &str) -> Result<(), String> {
     let trimmed = doc_id.trim();
 
     if trimmed.is_empty() {
@@ -253,8 +495,11 @@ fn validate_doc_id(doc_id: &str) -> Result<(), String> {
         return Err("document id contains invalid path characters".to_string());
     }
 
-    if !trimmed.ends_with(&format!(".{DOC_FILE_EXTENSION}")) {
-        return Err(format!("document id must end with .{DOC_FILE_EXTENSION}"));
+    if !has_doc_extension(trimmed) {
+        return Err(format!(
+            "document id must end with one of {}",
+            supported_doc_extensions()
+        ));
     }
 
     Ok(())
@@ -267,8 +512,21 @@ fn parse_doc_document(
 ) -> Result<DocDocument, String> {
     let file_contents = fs::read_to_string(path)
         .map_err(|error| format!("failed to read document file {}: {error}", path.display()))?;
-    let (frontmatter, markdown_body) = split_frontmatter(&file_contents, path)?;
-    let metadata = parse_frontmatter(&frontmatter, path)?;
+    let (frontmatter_syntax, frontmatter, markdown_body) = split_frontmatter(&file_contents, path)?;
+    let metadata = parse_frontmatter(frontmatter_syntax, &frontmatter, path)?;
+    let body_line_offset = frontmatter.matches('\n').count() + 3;
+    let markdown_source = doc_format_for_path(path)
+        .map_or(BodySyntax::Markdown, |format| format.body_syntax)
+        .markdown_source(&markdown_body);
+    let outline = extract_heading_outline(&markdown_source);
+    let tasks = extract_task_items(&markdown_source)
+        .into_iter()
+        .map(|task| MarkdownTask {
+            line: task.line + body_line_offset,
+            ..task
+        })
+        .collect::<Vec<MarkdownTask>>();
+    let tasks_done = tasks.iter().filter(|task| task.checked).count();
 
     let file_name = path
         .file_name()
@@ -301,21 +559,21 @@ fn parse_doc_document(
     let section = doc_path
         .split('/')
         .next()
-        .filter(|value| !value.ends_with(&format!(".{DOC_FILE_EXTENSION}")))
+        .filter(|value| !has_doc_extension(value))
         .unwrap_or("root")
         .to_string();
 
-    let display_title = metadata.title.clone().unwrap_or_else(|| {
-        file_name
-            .trim_end_matches(&format!(".{DOC_FILE_EXTENSION}"))
-            .to_string()
-    });
+    let display_title = metadata
+        .title
+        .clone()
+        .unwrap_or_else(|| strip_doc_extension(&file_name).to_string());
 
     let is_hidden = is_hidden_doc_path(&doc_path);
     let is_template = is_template_file(&file_name);
 
     Ok(DocDocument {
         id: doc_path.clone(),
+        revision: content_revision(&file_contents),
         file_name,
         doc_path,
         relative_path,
@@ -326,27 +584,37 @@ fn parse_doc_document(
         status: metadata.status,
         tags: metadata.tags,
         milestone: metadata.milestone,
+        related: metadata.related,
+        tasks_total: tasks.len(),
+        tasks_done,
+        tasks,
         is_template,
         is_hidden,
         markdown_body,
+        outline,
+        body_line_offset,
     })
 }
 
-fn split_frontmatter(contents: &str, path: &Path) -> Result<(String, String), String> {
+pub fn split_frontmatter(
+    contents: &str,
+    path: &Path,
+) -> Result<(FrontmatterSyntax, String, String), String> {
     let normalized_contents = contents.replace("\r\n", "\n");
 
-    if !normalized_contents.starts_with(&format!("{FRONTMATTER_DELIMITER}\n")) {
-        return Err(format!(
-            "document file {} must start with YAML frontmatter delimiter '---'",
+    let syntax = FrontmatterSyntax::detect(&normalized_contents).ok_or_else(|| {
+        format!(
+            "document file {} must start with YAML '---' or TOML '+++' frontmatter delimiter",
             path.display()
-        ));
-    }
+        )
+    })?;
+    let delimiter = syntax.delimiter();
 
-    let remainder = &normalized_contents[(FRONTMATTER_DELIMITER.len() + 1)..];
-    let delimiter_marker = format!("\n{FRONTMATTER_DELIMITER}\n");
+    let remainder = &normalized_contents[(delimiter.len() + 1)..];
+    let delimiter_marker = format!("\n{delimiter}\n");
     let delimiter_index = remainder.find(&delimiter_marker).ok_or_else(|| {
         format!(
-            "document file {} is missing closing frontmatter delimiter '---'",
+            "document file {} is missing closing frontmatter delimiter '{delimiter}'",
             path.display()
         )
     })?;
@@ -354,16 +622,46 @@ fn split_frontmatter(contents: &str, path: &Path) -> Result<(String, String), St
     let frontmatter = remainder[..delimiter_index].to_string();
     let markdown_body = remainder[(delimiter_index + delimiter_marker.len())..].to_string();
 
-    Ok((frontmatter, markdown_body))
+    Ok((syntax, frontmatter, markdown_body))
 }
 
-fn parse_frontmatter(frontmatter: &str, path: &Path) -> Result<ParsedMetadata, String> {
-    let parsed: DocFrontmatter = serde_yaml::from_str(frontmatter).map_err(|error| {
-        format!(
-            "failed to parse frontmatter for {}: {error}",
-            path.display()
-        )
-    })?;
+pub fn content_revision(contents: &str) -> String {
+    let hash = contents
+        .bytes()
+        .fold(REVISION_HASH_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(REVISION_HASH_PRIME)
+        });
+
+    format!("{hash:016x}")
+}
+
+pub fn compose_doc_contents(
+    syntax: FrontmatterSyntax,
+    frontmatter: &str,
+    markdown_body: &str,
+) -> String {
+    let delimiter = syntax.delimiter();
+    format!("{delimiter}\n{frontmatter}\n{delimiter}\n{markdown_body}")
+}
+
+pub fn validate_doc_contents(contents: &str, path: &Path) -> Result<(), String> {
+    let (syntax, frontmatter, _) = split_frontmatter(contents, path)?;
+    parse_frontmatter(syntax, &frontmatter, path).map(|_| ())
+}
+
+fn parse_frontmatter(
+    syntax: FrontmatterSyntax,
+    frontmatter: &str,
+    path: &Path,
+) -> Result<ParsedMetadata, String> {
+    let fields = syntax.parse_mapping(frontmatter, path)?;
+    let parsed: DocFrontmatter = serde_yaml::from_value(serde_yaml::Value::Mapping(fields))
+        .map_err(|error| {
+            format!(
+                "failed to parse frontmatter for {}: {error}",
+                path.display()
+            )
+        })?;
 
     let tags = parsed
         .tags
@@ -373,12 +671,28 @@ fn parse_frontmatter(frontmatter: &str, path: &Path) -> Result<ParsedMetadata, S
         .filter(|tag| !tag.is_empty())
         .collect::<Vec<String>>();
 
+    let related = [
+        parsed.related,
+        parsed.related_plan,
+        parsed.related_plans,
+        parsed.related_solutions,
+    ]
+    .into_iter()
+    .flatten()
+    .flat_map(|refs| match refs {
+        RelatedDocRefs::One(value) => vec![value],
+        RelatedDocRefs::Many(values) => values,
+    })
+    .filter_map(|value| optional_text(Some(value)))
+    .collect::<Vec<String>>();
+
     Ok(ParsedMetadata {
         title: optional_text(parsed.title),
         date: optional_text(parsed.date),
         status: optional_text(parsed.status),
         tags,
         milestone: optional_text(parsed.milestone),
+        related,
     })
 }
 
@@ -388,16 +702,12 @@ fn optional_text(value: Option<String>) -> Option<String> {
         .filter(|text| !text.is_empty())
 }
 
-fn is_markdown_file(path: &Path) -> bool {
-    path.is_file()
-        && path
-            .extension()
-            .and_then(|value| value.to_str())
-            .is_some_and(|value| value == DOC_FILE_EXTENSION)
+pub fn is_doc_file(path: &Path) -> bool {
+    path.is_file() && doc_format_for_path(path).is_some()
 }
 
-fn is_template_file(file_name: &str) -> bool {
-    file_name == ".template.md" || file_name.ends_with(".template.md")
+pub fn is_template_file(file_name: &str) -> bool {
+    has_doc_extension(file_name) && strip_doc_extension(file_name).ends_with(".template")
 }
 
 fn is_hidden_doc_path(doc_path: &str) -> bool {
@@ -1,38 +1,15 @@
 use super::{
-    get_doc_document_from_root, list_doc_summaries_from_root, parse_doc_document, Path, PathBuf,
+    get_doc_document_from_root, get_doc_section_from_root, list_doc_summaries_from_root,
+    parse_doc_document,
 };
-use std::fs;
-use std::time::{SystemTime, UNIX_EPOCH};
-
-fn create_temp_workspace() -> PathBuf {
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .expect("system clock should be after unix epoch")
-        .as_nanos();
-    let workspace_path = std::env::temp_dir().join(format!("coda-doc-viewer-tests-{timestamp}"));
-    let docs_path = workspace_path.join("docs");
-
-    fs::create_dir_all(&docs_path).expect("temp workspace should be created");
-
-    workspace_path
-        .canonicalize()
-        .expect("temp workspace should canonicalize")
-}
-
-fn write_doc_file(path: &Path, contents: &str) {
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent).expect("parent directory should be created");
-    }
-    fs::write(path, contents).expect("document file should be written");
-}
-
-fn teardown_workspace(path: &Path) {
-    fs::remove_dir_all(path).expect("temp workspace should be removed");
-}
+use crate::doc_ignore::DocIgnoreRules;
+use crate::doc_scan::{never_cancelled, DOC_SCAN_CANCELLED_ERROR};
+use crate::doc_summary_cache::DocSummaryCache;
+use crate::test_support::{create_temp_workspace, teardown_workspace, write_doc_file};
 
 #[test]
 fn parses_valid_doc_file_into_document() {
-    let workspace = create_temp_workspace();
+    let workspace = create_temp_workspace("doc-viewer");
     let docs_root = workspace.join("docs");
     let doc_path = docs_root
         .join("solutions")
@@ -60,7 +37,7 @@ fn parses_valid_doc_file_into_document() {
 
 #[test]
 fn allows_optional_metadata_for_template_doc() {
-    let workspace = create_temp_workspace();
+    let workspace = create_temp_workspace("doc-viewer");
     let docs_root = workspace.join("docs");
     let doc_path = docs_root.join("plans").join(".template.md");
 
@@ -86,7 +63,7 @@ fn allows_optional_metadata_for_template_doc() {
 
 #[test]
 fn reports_file_path_for_malformed_frontmatter() {
-    let workspace = create_temp_workspace();
+    let workspace = create_temp_workspace("doc-viewer");
     let docs_root = workspace.join("docs");
     let doc_path = docs_root.join("broken.md");
 
@@ -109,7 +86,7 @@ fn reports_file_path_for_malformed_frontmatter() {
 
 #[test]
 fn rejects_path_traversal_document_ids() {
-    let workspace = create_temp_workspace();
+    let workspace = create_temp_workspace("doc-viewer");
     let docs_root = workspace.join("docs");
 
     let error = get_doc_document_from_root(&workspace, &docs_root, "../outside.md")
@@ -122,7 +99,7 @@ fn rejects_path_traversal_document_ids() {
 
 #[test]
 fn lists_docs_recursively_and_filters_hidden_by_default() {
-    let workspace = create_temp_workspace();
+    let workspace = create_temp_workspace("doc-viewer");
     let docs_root = workspace.join("docs");
 
     write_doc_file(
@@ -138,15 +115,29 @@ fn lists_docs_recursively_and_filters_hidden_by_default() {
         "---\ntitle:\ndate:\nstatus: draft\ntags: []\n---\n\nTemplate\n",
     );
 
-    let default_summaries =
-        list_doc_summaries_from_root(&workspace, &docs_root, false).expect("listing should work");
+    let default_summaries = list_doc_summaries_from_root(
+        &workspace,
+        &docs_root,
+        &[],
+        false,
+        &mut DocSummaryCache::default(),
+        &never_cancelled,
+    )
+    .expect("listing should work");
     assert_eq!(default_summaries.len(), 2);
     assert!(default_summaries
         .iter()
         .all(|summary| summary.id != "plans/.template.md"));
 
-    let expanded_summaries =
-        list_doc_summaries_from_root(&workspace, &docs_root, true).expect("listing should work");
+    let expanded_summaries = list_doc_summaries_from_root(
+        &workspace,
+        &docs_root,
+        &[],
+        true,
+        &mut DocSummaryCache::default(),
+        &never_cancelled,
+    )
+    .expect("listing should work");
     assert_eq!(expanded_summaries.len(), 3);
     assert!(expanded_summaries
         .iter()
@@ -154,3 +145,264 @@ fn lists_docs_recursively_and_filters_hidden_by_default() {
 
     teardown_workspace(&workspace);
 }
+
+#[test]
+fn returns_heading_outline_and_section_by_slug() {
+    let workspace = create_temp_workspace("doc-viewer");
+    let docs_root = workspace.join("docs");
+
+    write_doc_file(
+        &docs_root.join("PRD.md"),
+        "---\ntitle: PRD\ndate: 2026-02-13\n---\n\n## Goals\n\nShip.\n\n### Non-goals\n\nNone.\n\n## Milestones\n\nM1.\n",
+    );
+
+    let document =
+        get_doc_document_from_root(&workspace, &docs_root, "PRD.md").expect("doc should load");
+    assert_eq!(
+        document
+            .outline
+            .iter()
+            .map(|heading| heading.slug.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["goals", "non-goals", "milestones"]
+    );
+
+    let section = get_doc_section_from_root(&workspace, &docs_root, "PRD.md", "#goals")
+        .expect("section should resolve");
+    assert_eq!(section.heading.level, 2);
+    assert_eq!(
+        section.markdown,
+        "## Goals\n\nShip.\n\n### Non-goals\n\nNone.\n\n"
+    );
+
+    let error = get_doc_section_from_root(&workspace, &docs_root, "PRD.md", "missing")
+        .expect_err("unknown slug should fail");
+    assert!(error.contains("section 'missing' not found"));
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn parses_markdown_and_mdx_formats_with_toml_frontmatter() {
+    let workspace = create_temp_workspace("doc-viewer");
+    let docs_root = workspace.join("docs");
+
+    write_doc_file(
+        &docs_root.join("guides").join("setup.markdown"),
+        "+++\ntitle = \"Setup\"\ndate = 2026-02-19\ntags = [\"ops\"]\n+++\n\n## Install\n\n- [x] clone\n",
+    );
+    write_doc_file(
+        &docs_root.join("guides").join("chart.mdx"),
+        "---\ndate: 2026-02-18\n---\nimport Chart from './chart'\n\n## Usage\n\n<Chart />\n",
+    );
+    write_doc_file(&docs_root.join("guides").join("notes.txt"), "ignored\n");
+
+    let summaries = list_doc_summaries_from_root(
+        &workspace,
+        &docs_root,
+        &[],
+        false,
+        &mut DocSummaryCache::default(),
+        &never_cancelled,
+    )
+    .expect("listing should work");
+    assert_eq!(
+        summaries
+            .iter()
+            .map(|summary| summary.id.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["guides/setup.markdown", "guides/chart.mdx"]
+    );
+
+    let setup = get_doc_document_from_root(&workspace, &docs_root, "guides/setup.markdown")
+        .expect("markdown document should load");
+    assert_eq!(setup.title.as_deref(), Some("Setup"));
+    assert_eq!(setup.date.as_deref(), Some("2026-02-19"));
+    assert_eq!(setup.tags, vec!["ops"]);
+    assert_eq!(setup.tasks_done, 1);
+
+    let chart = get_doc_document_from_root(&workspace, &docs_root, "guides/chart.mdx")
+        .expect("mdx document should load");
+    assert_eq!(chart.display_title, "chart");
+    assert_eq!(
+        chart
+            .outline
+            .iter()
+            .map(|heading| heading.slug.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["usage"]
+    );
+    assert!(chart.markdown_body.starts_with("import Chart"));
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn applies_nested_and_repository_ignore_files() {
+    let repository = create_temp_workspace("doc-viewer");
+    let workspace = repository.join("app");
+    let docs_root = workspace.join("docs");
+
+    write_doc_file(&repository.join(".git/info/exclude"), "*.secret.md\n");
+    write_doc_file(&repository.join(".gitignore"), "scratch/\n");
+    write_doc_file(&docs_root.join("plans/.gitignore"), "*.local.md\n");
+    write_doc_file(
+        &docs_root.join("plans/active/.codaignore"),
+        "!keep.local.md\n",
+    );
+    for doc_id in [
+        "PRD.md",
+        "notes.secret.md",
+        "scratch/todo.md",
+        "top.local.md",
+        "plans/idea.local.md",
+        "plans/active/keep.local.md",
+        "plans/active/other.local.md",
+    ] {
+        write_doc_file(&docs_root.join(doc_id), "---\ntitle: Doc\n---\n\nBody\n");
+    }
+
+    let summaries = list_doc_summaries_from_root(
+        &workspace,
+        &docs_root,
+        &[],
+        false,
+        &mut DocSummaryCache::default(),
+        &never_cancelled,
+    )
+    .expect("listing should work");
+    let mut doc_ids = summaries
+        .iter()
+        .map(|summary| summary.id.as_str())
+        .collect::<Vec<&str>>();
+    doc_ids.sort_unstable();
+    assert_eq!(
+        doc_ids,
+        vec!["PRD.md", "plans/active/keep.local.md", "top.local.md"]
+    );
+
+    let ignore_rules = DocIgnoreRules::load(&workspace, std::slice::from_ref(&docs_root), &[]);
+    assert!(ignore_rules.is_ignored(&docs_root.join("plans/idea.local.md"), false));
+    assert!(!ignore_rules.is_ignored(&docs_root.join("plans/active/keep.local.md"), false));
+    assert!(ignore_rules.is_ignored(&docs_root.join("notes.secret.md"), false));
+
+    teardown_workspace(&repository);
+}
+
+#[test]
+fn skips_ignored_paths() {
+    let workspace = create_temp_workspace("doc-viewer");
+    let docs_root = workspace.join("docs");
+
+    write_doc_file(&workspace.join(".gitignore"), "node_modules/\n");
+    write_doc_file(
+        &docs_root.join(".codaignore"),
+        "drafts/\n!drafts/\n*.wip.md\n",
+    );
+    write_doc_file(
+        &docs_root.join("PRD.md"),
+        "---\ntitle: PRD\ndate: 2026-02-13\n---\n\nBody\n",
+    );
+    write_doc_file(
+        &docs_root.join("node_modules").join("pkg").join("README.md"),
+        "---\ntitle: Package\n---\n",
+    );
+    write_doc_file(
+        &docs_root.join("plans").join("idea.wip.md"),
+        "---\ntitle: Idea\n---\n",
+    );
+    write_doc_file(
+        &docs_root.join("drafts").join("outline.md"),
+        "---\ntitle: Outline\n---\n",
+    );
+    write_doc_file(
+        &docs_root.join("archive").join("old.md"),
+        "---\ntitle: Old\n---\n",
+    );
+
+    let summaries = list_doc_summaries_from_root(
+        &workspace,
+        &docs_root,
+        &["docs/archive/**".to_string()],
+        false,
+        &mut DocSummaryCache::default(),
+        &never_cancelled,
+    )
+    .expect("listing should work");
+    assert_eq!(
+        summaries
+            .iter()
+            .map(|summary| summary.id.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["drafts/outline.md", "PRD.md"]
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[cfg(unix)]
+#[test]
+fn skips_symlinked_directory_loops() {
+    let workspace = create_temp_workspace("doc-viewer");
+    let docs_root = workspace.join("docs");
+
+    write_doc_file(
+        &docs_root.join("plans").join("plan.md"),
+        "---\ntitle: Plan\n---\n",
+    );
+    std::os::unix::fs::symlink(&docs_root, docs_root.join("plans").join("loop"))
+        .expect("symlink should be created");
+
+    let summaries = list_doc_summaries_from_root(
+        &workspace,
+        &docs_root,
+        &[],
+        false,
+        &mut DocSummaryCache::default(),
+        &never_cancelled,
+    )
+    .expect("listing should work");
+    assert_eq!(
+        summaries
+            .iter()
+            .map(|summary| summary.id.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["plans/plan.md"]
+    );
+
+    teardown_workspace(&workspace);
+}
+
+#[test]
+fn stops_listing_when_scan_is_cancelled() {
+    let workspace = create_temp_workspace("doc-viewer");
+    let docs_root = workspace.join("docs");
+
+    for index in 0..50 {
+        write_doc_file(
+            &docs_root
+                .join(format!("section-{}", index % 5))
+                .join(format!("doc-{index}.md")),
+            "---\ntitle: Doc\ndate: 2026-02-13\n---\n\nBody\n",
+        );
+    }
+
+    let mut cache = DocSummaryCache::default();
+    let error =
+        list_doc_summaries_from_root(&workspace, &docs_root, &[], false, &mut cache, &|| true)
+            .expect_err("cancelled listing should fail");
+    assert_eq!(error, DOC_SCAN_CANCELLED_ERROR);
+
+    let summaries = list_doc_summaries_from_root(
+        &workspace,
+        &docs_root,
+        &[],
+        false,
+        &mut cache,
+        &never_cancelled,
+    )
+    .expect("listing should work");
+    assert_eq!(summaries.len(), 50);
+
+    teardown_workspace(&workspace);
+}
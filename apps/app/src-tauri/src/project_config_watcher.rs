@@ -0,0 +1,243 @@
+use crate::docs_watcher::DocsWatcherState;
+use crate::project_runtime::{reload_projects, ProjectRegistryState};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::io::Error;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use tauri::AppHandle;
+
+const CONFIG_WATCH_DEBOUNCE_WINDOW: Duration = Duration::from_millis(180);
+const CONFIG_WATCHER_THREAD_NAME: &str = "coda-config-watcher";
+
+pub fn start_project_config_watcher(
+    app_handle: AppHandle,
+    registry_state: &ProjectRegistryState,
+    watcher_state: &DocsWatcherState,
+) -> Result<(), Error> {
+    let config_paths = registry_state.config_paths().map_err(Error::other)?;
+    let (event_sender, event_receiver) = mpsc::channel::<notify::Result<Event>>();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        if event_sender.send(event).is_err() {
+            log::warn!("project config watcher receiver dropped");
+        }
+    })
+    .map_err(Error::other)?;
+
+    let mut watched_dirs = BTreeSet::new();
+    watch_config_dirs(&mut watcher, &config_paths, &mut watched_dirs);
+
+    let registry_state = registry_state.clone();
+    let watcher_state = watcher_state.clone();
+    thread::Builder::new()
+        .name(CONFIG_WATCHER_THREAD_NAME.to_string())
+        .spawn(move || {
+            watch_config_loop(
+                &app_handle,
+                &registry_state,
+                &watcher_state,
+                &config_paths,
+                &mut watcher,
+                &mut watched_dirs,
+                event_receiver,
+            );
+        })
+        .map(|_| ())
+}
+
+fn watch_config_loop(
+    app_handle: &AppHandle,
+    registry_state: &ProjectRegistryState,
+    watcher_state: &DocsWatcherState,
+    config_paths: &[PathBuf],
+    watcher: &mut impl Watcher,
+    watched_dirs: &mut BTreeSet<PathBuf>,
+    event_receiver: mpsc::Receiver<notify::Result<Event>>,
+) {
+    while let Ok(first_event) = event_receiver.recv() {
+        let mut pending_events = vec![first_event];
+        while let Ok(event) = event_receiver.recv_timeout(CONFIG_WATCH_DEBOUNCE_WINDOW) {
+            pending_events.push(event);
+        }
+
+        let config_dir_appeared = watch_config_dirs(watcher, config_paths, watched_dirs);
+        if !config_dir_appeared
+            && !pending_events
+                .iter()
+                .any(|event| touches_config_file(event, config_paths))
+        {
+            continue;
+        }
+
+        match reload_projects(app_handle, registry_state, watcher_state) {
+            Ok(changes) if !changes.is_empty() => log::info!(
+                "project registry reloaded: {} added, {} removed, {} updated",
+                changes.added_project_ids.len(),
+                changes.removed_project_ids.len(),
+                changes.updated_project_ids.len()
+            ),
+            Ok(_) => {}
+            Err(error) => log::warn!("{error}"),
+        }
+    }
+}
+
+fn config_dirs(config_paths: &[PathBuf]) -> BTreeSet<PathBuf> {
+    config_paths
+        .iter()
+        .filter_map(|config_path| config_path.parent())
+        .map(Path::to_path_buf)
+        .collect()
+}
+
+fn watch_config_dirs(
+    watcher: &mut impl Watcher,
+    config_paths: &[PathBuf],
+    watched_dirs: &mut BTreeSet<PathBuf>,
+) -> bool {
+    let config_dirs = config_dirs(config_paths);
+    let next_watched_dirs = config_dirs
+        .iter()
+        .filter_map(|config_dir| nearest_existing_dir(config_dir))
+        .collect::<BTreeSet<PathBuf>>();
+
+    watched_dirs.retain(|watched_dir| {
+        if next_watched_dirs.contains(watched_dir) {
+            return true;
+        }
+        if let Err(error) = watcher.unwatch(watched_dir) {
+            log::debug!(
+                "project config watcher cannot unwatch {}: {error}",
+                watched_dir.display()
+            );
+        }
+        false
+    });
+
+    let mut config_dir_appeared = false;
+    for watch_dir in next_watched_dirs {
+        if watched_dirs.contains(&watch_dir) {
+            continue;
+        }
+        if let Err(error) = watcher.watch(&watch_dir, RecursiveMode::NonRecursive) {
+            log::warn!(
+                "project config watcher cannot watch {}: {error}",
+                watch_dir.display()
+            );
+            continue;
+        }
+
+        config_dir_appeared |= config_dirs.contains(&watch_dir);
+        watched_dirs.insert(watch_dir);
+    }
+
+    config_dir_appeared
+}
+
+fn nearest_existing_dir(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .find(|ancestor| ancestor.is_dir())
+        .map(Path::to_path_buf)
+}
+
+fn canonical_path(path: &Path) -> PathBuf {
+    path.ancestors()
+        .find_map(|ancestor| {
+            let relative_path = path.strip_prefix(ancestor).ok()?;
+            Some(ancestor.canonicalize().ok()?.join(relative_path))
+        })
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+fn touches_config_file(event: &notify::Result<Event>, config_paths: &[PathBuf]) -> bool {
+    match event {
+        Ok(event) => {
+            if matches!(event.kind, EventKind::Access(_)) {
+                return false;
+            }
+
+            let config_paths = config_paths
+                .iter()
+                .map(|config_path| canonical_path(config_path))
+                .collect::<Vec<PathBuf>>();
+            event
+                .paths
+                .iter()
+                .any(|path| config_paths.contains(&canonical_path(path)))
+        }
+        Err(error) => {
+            log::warn!("project config watcher event dropped: {error}");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::touches_config_file;
+    use notify::event::{AccessKind, CreateKind, DataChange, ModifyKind};
+    use notify::{Event, EventKind};
+    use std::path::PathBuf;
+
+    #[test]
+    fn reacts_only_to_config_file_writes() {
+        let config_paths = vec![
+            PathBuf::from("/tmp/home/.coda/config.toml"),
+            PathBuf::from("/tmp/workspace/.coda/config.toml"),
+        ];
+
+        assert!(touches_config_file(
+            &Ok(
+                Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
+                    .add_path(PathBuf::from("/tmp/home/.coda/config.toml"))
+            ),
+            &config_paths
+        ));
+        assert!(touches_config_file(
+            &Ok(Event::new(EventKind::Create(CreateKind::File))
+                .add_path(PathBuf::from("/tmp/workspace/.coda/config.toml"))),
+            &config_paths
+        ));
+        assert!(!touches_config_file(
+            &Ok(
+                Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
+                    .add_path(PathBuf::from("/tmp/home/.coda/app-state.toml"))
+            ),
+            &config_paths
+        ));
+        assert!(!touches_config_file(
+            &Ok(Event::new(EventKind::Access(AccessKind::Any))
+                .add_path(PathBuf::from("/tmp/home/.coda/config.toml"))),
+            &config_paths
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn matches_config_paths_reported_through_symlinked_directories() {
+        use crate::test_support::{create_temp_workspace, teardown_workspace};
+        use std::fs;
+        use std::os::unix::fs::symlink;
+
+        let temp_root = create_temp_workspace("config-watcher");
+        let real_home = temp_root.join("real-home");
+        fs::create_dir_all(real_home.join(".coda")).expect("config dir should be created");
+        symlink(&real_home, temp_root.join("home")).expect("symlink should be created");
+
+        let config_paths = vec![temp_root.join("home/.coda/config.toml")];
+        assert!(touches_config_file(
+            &Ok(Event::new(EventKind::Create(CreateKind::File))
+                .add_path(real_home.join(".coda/config.toml"))),
+            &config_paths
+        ));
+        assert!(!touches_config_file(
+            &Ok(Event::new(EventKind::Create(CreateKind::File))
+                .add_path(real_home.join(".coda/app-state.toml"))),
+            &config_paths
+        ));
+
+        teardown_workspace(&temp_root);
+    }
+}
@@ -1,11 +1,9 @@
-use crate::project_registry::{ProjectContext, ProjectRegistry};
+use crate::project_registry::{resolve_docs_roots, ProjectContext, ProjectRegistry};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
-const DOCS_DIR_NAME: &str = "docs";
-
 #[derive(Debug, Deserialize, Serialize, Default)]
 struct GlobalConfigFile {
     projects: Option<BTreeMap<String, GlobalProjectConfig>>,
@@ -18,6 +16,8 @@ struct GlobalConfigFile {
 struct GlobalProjectConfig {
     path: String,
     display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docs_roots: Option<Vec<String>>,
     #[serde(flatten)]
     extra: BTreeMap<String, toml::Value>,
 }
@@ -25,6 +25,7 @@ struct GlobalProjectConfig {
 pub fn build_project_registration_candidate(
     registry: &ProjectRegistry,
     root_path: &str,
+    docs_roots: Option<&[String]>,
 ) -> Result<ProjectContext, String> {
     let normalized_root_path = normalize_required_text(root_path, "root_path")?;
     let canonical_root_path = canonicalize_project_root(Path::new(&normalized_root_path))?;
@@ -40,13 +41,8 @@ pub fn build_project_registration_candidate(
         ));
     }
 
-    let docs_path = canonical_root_path.join(DOCS_DIR_NAME);
-    if !docs_path.exists() || !docs_path.is_dir() {
-        return Err(format!(
-            "project registration failed: docs directory does not exist under root path '{}'.",
-            canonical_root_path.display()
-        ));
-    }
+    let docs_roots = resolve_docs_roots(&canonical_root_path, docs_roots)?;
+    let docs_path = docs_roots[0].path.clone();
 
     let existing_ids = registry
         .projects
@@ -60,6 +56,8 @@ pub fn build_project_registration_candidate(
         display_name: derive_default_display_name(&canonical_root_path),
         root_path: canonical_root_path,
         docs_path,
+        docs_roots,
+        docs_exclude: Vec::new(),
         has_local_override: false,
     })
 }
@@ -67,8 +65,9 @@ pub fn build_project_registration_candidate(
 pub fn persist_registered_project(
     global_config_path: &Path,
     project: &ProjectContext,
+    docs_roots: Option<&[String]>,
 ) -> Result<(), String> {
-    let mut config = read_global_config(global_config_path)?;
+    let mut config = read_global_config(global_config_path, "project registration")?;
     let projects = config.projects.get_or_insert_with(BTreeMap::new);
 
     if projects.contains_key(&project.project_id) {
@@ -83,11 +82,33 @@ pub fn persist_registered_project(
         GlobalProjectConfig {
             path: project.root_path.to_string_lossy().to_string(),
             display_name: Some(project.display_name.clone()),
+            docs_roots: docs_roots.map(<[String]>::to_vec),
             extra: BTreeMap::new(),
         },
     );
 
-    write_global_config_atomic(global_config_path, &config)
+    write_global_config_atomic(global_config_path, &config, "project registration")
+}
+
+pub fn remove_registered_project(
+    global_config_path: &Path,
+    project_id: &str,
+) -> Result<(), String> {
+    let mut config = read_global_config(global_config_path, "project removal")?;
+    let removed = config
+        .projects
+        .as_mut()
+        .and_then(|projects| projects.remove(project_id));
+
+    if removed.is_none() {
+        return Err(format!(
+            "project removal failed: project_id '{}' is not registered in global config {}.",
+            project_id,
+            global_config_path.display()
+        ));
+    }
+
+    write_global_config_atomic(global_config_path, &config, "project removal")
 }
 
 fn canonicalize_project_root(root_path: &Path) -> Result<PathBuf, String> {
@@ -171,31 +192,35 @@ fn normalize_required_text(value: &str, label: &str) -> Result<String, String> {
     Ok(normalized)
 }
 
-fn read_global_config(config_path: &Path) -> Result<GlobalConfigFile, String> {
+fn read_global_config(config_path: &Path, action: &str) -> Result<GlobalConfigFile, String> {
     if !config_path.exists() {
         return Ok(GlobalConfigFile::default());
     }
 
     let contents = fs::read_to_string(config_path).map_err(|error| {
         format!(
-            "project registration failed: cannot read global config {}: {error}",
+            "{action} failed: cannot read global config {}: {error}",
             config_path.display()
         )
     })?;
 
     toml::from_str::<GlobalConfigFile>(&contents).map_err(|error| {
         format!(
-            "project registration failed: cannot parse global config {}: {error}",
+            "{action} failed: cannot parse global config {}: {error}",
             config_path.display()
         )
     })
 }
 
-fn write_global_config_atomic(config_path: &Path, config: &GlobalConfigFile) -> Result<(), String> {
+fn write_global_config_atomic(
+    config_path: &Path,
+    config: &GlobalConfigFile,
+    action: &str,
+) -> Result<(), String> {
     if let Some(parent) = config_path.parent() {
         fs::create_dir_all(parent).map_err(|error| {
             format!(
-                "project registration failed: cannot prepare global config directory {}: {error}",
+                "{action} failed: cannot prepare global config directory {}: {error}",
                 parent.display()
             )
         })?;
@@ -203,7 +228,7 @@ fn write_global_config_atomic(config_path: &Path, config: &GlobalConfigFile) ->
 
     let serialized = toml::to_string_pretty(config).map_err(|error| {
         format!(
-            "project registration failed: cannot serialize global config {}: {error}",
+            "{action} failed: cannot serialize global config {}: {error}",
             config_path.display()
         )
     })?;
@@ -219,14 +244,14 @@ fn write_global_config_atomic(config_path: &Path, config: &GlobalConfigFile) ->
 
     fs::write(&temporary_path, serialized).map_err(|error| {
         format!(
-            "project registration failed: cannot write global config temporary file {}: {error}",
+            "{action} failed: cannot write global config temporary file {}: {error}",
             temporary_path.display()
         )
     })?;
 
     fs::rename(&temporary_path, config_path).map_err(|error| {
         format!(
-            "project registration failed: cannot atomically replace global config {}: {error}",
+            "{action} failed: cannot atomically replace global config {}: {error}",
             config_path.display()
         )
     })
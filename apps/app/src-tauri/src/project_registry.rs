@@ -2,8 +2,12 @@ use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-const DOCS_DIR_NAME: &str = "docs";
+const DEFAULT_DOCS_ROOT: &str = "docs";
+const DEFAULT_WATCHER_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const MIN_WATCHER_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const DOC_NAMESPACE_SEPARATOR: char = ':';
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -12,15 +16,31 @@ pub struct ProjectSummary {
     pub display_name: String,
     pub root_path: String,
     pub docs_path: String,
+    pub docs_roots: Vec<DocsRootSummary>,
     pub has_local_override: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocsRootSummary {
+    pub namespace: Option<String>,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocsRoot {
+    pub namespace: Option<String>,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProjectContext {
     pub project_id: String,
     pub display_name: String,
     pub root_path: PathBuf,
     pub docs_path: PathBuf,
+    pub docs_roots: Vec<DocsRoot>,
+    pub docs_exclude: Vec<String>,
     pub has_local_override: bool,
 }
 
@@ -28,6 +48,20 @@ pub struct ProjectContext {
 pub struct ProjectRegistry {
     pub projects: Vec<ProjectContext>,
     pub preferred_active_project_id: Option<String>,
+    pub watcher_settings: WatcherSettings,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WatcherBackend {
+    Native,
+    Poll,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatcherSettings {
+    pub backend: WatcherBackend,
+    pub poll_interval: Duration,
 }
 
 #[derive(Debug, Clone)]
@@ -35,6 +69,8 @@ struct ProjectCandidate {
     project_id: String,
     display_name: Option<String>,
     root_path: PathBuf,
+    docs_roots: Option<Vec<String>>,
+    docs_exclude: Option<Vec<String>>,
     has_local_override: bool,
 }
 
@@ -42,12 +78,15 @@ struct ProjectCandidate {
 struct GlobalConfigFile {
     projects: Option<BTreeMap<String, GlobalProjectConfig>>,
     app: Option<GlobalAppConfig>,
+    watcher: Option<GlobalWatcherConfig>,
 }
 
 #[derive(Debug, Deserialize)]
 struct GlobalProjectConfig {
     path: String,
     display_name: Option<String>,
+    docs_roots: Option<Vec<String>>,
+    docs_exclude: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -55,6 +94,12 @@ struct GlobalAppConfig {
     active_project_id: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct GlobalWatcherConfig {
+    backend: Option<WatcherBackend>,
+    poll_interval_ms: Option<u64>,
+}
+
 #[derive(Debug, Deserialize, Default)]
 struct LocalConfigFile {
     project: Option<LocalProjectOverride>,
@@ -64,6 +109,8 @@ struct LocalConfigFile {
 struct LocalProjectOverride {
     id: Option<String>,
     display_name: Option<String>,
+    docs_roots: Option<Vec<String>>,
+    docs_exclude: Option<Vec<String>>,
 }
 
 pub fn load_project_registry_from_paths(
@@ -86,6 +133,7 @@ pub fn load_project_registry_from_paths(
     apply_local_override(&mut candidates, &current_workspace_canonical, &local)?;
 
     let projects = validate_project_candidates(candidates)?;
+    let watcher_settings = resolve_watcher_settings(global.watcher.as_ref());
     let preferred_active_project_id = global
         .app
         .and_then(|app| normalize_optional_text(app.active_project_id));
@@ -93,6 +141,7 @@ pub fn load_project_registry_from_paths(
     Ok(ProjectRegistry {
         projects,
         preferred_active_project_id,
+        watcher_settings,
     })
 }
 
@@ -114,9 +163,9 @@ pub fn validate_project_selection(
         })
 }
 
-#[cfg_attr(not(test), allow(dead_code))]
 pub fn validate_project_removal(
     registry: &ProjectRegistry,
+    current_workspace_root: &Path,
     active_project_id: &str,
     removed_project_id: &str,
 ) -> Result<(), String> {
@@ -136,6 +185,16 @@ pub fn validate_project_removal(
         ));
     }
 
+    let current_workspace_root = current_workspace_root.canonicalize().ok();
+    if current_workspace_root.is_some()
+        && removed_project.root_path.canonicalize().ok() == current_workspace_root
+    {
+        return Err(format!(
+            "project removal failed: cannot remove project '{}' for the current workspace.",
+            removed_project.project_id
+        ));
+    }
+
     Ok(())
 }
 
@@ -157,6 +216,8 @@ fn global_project_candidates(global: &GlobalConfigFile) -> Result<Vec<ProjectCan
                 project_id: normalize_required_text(project_id, "project_id")?,
                 display_name: normalize_optional_text(project.display_name.clone()),
                 root_path: PathBuf::from(project.path.trim()),
+                docs_roots: project.docs_roots.clone(),
+                docs_exclude: project.docs_exclude.clone(),
                 has_local_override: false,
             });
         }
@@ -193,6 +254,8 @@ fn ensure_current_project_candidate(
         project_id: derive_default_project_id(current_workspace_root, &existing_ids),
         display_name: Some(derive_default_display_name(current_workspace_root)),
         root_path: PathBuf::from(current_workspace_root_string),
+        docs_roots: None,
+        docs_exclude: None,
         has_local_override: false,
     });
 }
@@ -224,6 +287,14 @@ fn apply_local_override(
         current_project.display_name = Some(local_display_name);
     }
 
+    if let Some(local_docs_roots) = &project_override.docs_roots {
+        current_project.docs_roots = Some(local_docs_roots.clone());
+    }
+
+    if let Some(local_docs_exclude) = &project_override.docs_exclude {
+        current_project.docs_exclude = Some(local_docs_exclude.clone());
+    }
+
     current_project.has_local_override = true;
     Ok(())
 }
@@ -253,13 +324,8 @@ fn validate_project_candidates(
             ));
         }
 
-        let docs_path = root_path.join(DOCS_DIR_NAME);
-        if !docs_path.exists() || !docs_path.is_dir() {
-            return Err(format!(
-                "project registration failed: docs directory does not exist under root path '{}'.",
-                root_path.display()
-            ));
-        }
+        let docs_roots = resolve_docs_roots(&root_path, candidate.docs_roots.as_deref())?;
+        let docs_path = docs_roots[0].path.clone();
 
         projects.push(ProjectContext {
             project_id: candidate.project_id,
@@ -268,6 +334,8 @@ fn validate_project_candidates(
                 .unwrap_or_else(|| derive_default_display_name(&root_path)),
             root_path,
             docs_path,
+            docs_roots,
+            docs_exclude: candidate.docs_exclude.unwrap_or_default(),
             has_local_override: candidate.has_local_override,
         });
     }
@@ -282,6 +350,112 @@ fn validate_project_candidates(
     Ok(projects)
 }
 
+pub fn resolve_docs_roots(
+    root_path: &Path,
+    configured_roots: Option<&[String]>,
+) -> Result<Vec<DocsRoot>, String> {
+    let default_roots = [DEFAULT_DOCS_ROOT.to_string()];
+    let configured_roots = configured_roots.unwrap_or(&default_roots);
+
+    if configured_roots.is_empty() {
+        return Err(format!(
+            "project registration failed: docs_roots must not be empty for root path '{}'.",
+            root_path.display()
+        ));
+    }
+
+    let mut seen_paths = BTreeSet::<PathBuf>::new();
+    let mut docs_roots = Vec::new();
+
+    for (index, configured_root) in configured_roots.iter().enumerate() {
+        let normalized_root = configured_root.trim().trim_end_matches('/').to_string();
+        let is_valid = !normalized_root.is_empty()
+            && !Path::new(&normalized_root).is_absolute()
+            && !normalized_root.contains("..")
+            && !normalized_root.contains('\\')
+            && !normalized_root.contains(DOC_NAMESPACE_SEPARATOR);
+
+        if !is_valid {
+            return Err(format!(
+                "project registration failed: invalid docs root '{}'. Use a relative directory under the project root.",
+                configured_root
+            ));
+        }
+
+        let docs_path = root_path.join(&normalized_root);
+        if !docs_path.is_dir() {
+            return Err(format!(
+                "project registration failed: docs directory does not exist under root path '{}' (docs root '{}').",
+                root_path.display(),
+                normalized_root
+            ));
+        }
+
+        let docs_path = docs_path.canonicalize().map_err(|error| {
+            format!(
+                "project registration failed: cannot resolve docs directory {}: {error}",
+                docs_path.display()
+            )
+        })?;
+
+        if !docs_path.starts_with(root_path) {
+            return Err(format!(
+                "project registration failed: docs directory '{}' resolves outside root path '{}'.",
+                normalized_root,
+                root_path.display()
+            ));
+        }
+
+        if !seen_paths.insert(docs_path.clone()) {
+            return Err(format!(
+                "project registration failed: duplicate docs root '{}'.",
+                normalized_root
+            ));
+        }
+
+        if docs_roots.iter().any(|docs_root: &DocsRoot| {
+            docs_root.path.starts_with(&docs_path) || docs_path.starts_with(&docs_root.path)
+        }) {
+            return Err(format!(
+                "project registration failed: docs root '{}' overlaps another docs root.",
+                normalized_root
+            ));
+        }
+
+        docs_roots.push(DocsRoot {
+            namespace: (index > 0).then_some(normalized_root),
+            path: docs_path,
+        });
+    }
+
+    Ok(docs_roots)
+}
+
+fn resolve_watcher_settings(watcher: Option<&GlobalWatcherConfig>) -> WatcherSettings {
+    let mut settings = WatcherSettings::default();
+    let Some(watcher) = watcher else {
+        return settings;
+    };
+
+    if let Some(backend) = watcher.backend {
+        settings.backend = backend;
+    }
+
+    if let Some(poll_interval_ms) = watcher.poll_interval_ms {
+        let poll_interval = Duration::from_millis(poll_interval_ms);
+        if poll_interval < MIN_WATCHER_POLL_INTERVAL {
+            log::warn!(
+                "watcher poll_interval_ms {poll_interval_ms} is below {}; using {} instead",
+                MIN_WATCHER_POLL_INTERVAL.as_millis(),
+                MIN_WATCHER_POLL_INTERVAL.as_millis()
+            );
+        }
+        settings.poll_interval = poll_interval.max(MIN_WATCHER_POLL_INTERVAL);
+    }
+
+    settings
+}
+
 fn canonicalize_project_root(root_path: &Path) -> Result<PathBuf, String> {
     if !root_path.exists() {
         return Err(format!(
@@ -438,9 +612,66 @@ impl ProjectContext {
             display_name: self.display_name.clone(),
             root_path: self.root_path.to_string_lossy().to_string(),
             docs_path: self.docs_path.to_string_lossy().to_string(),
+            docs_roots: self
+                .docs_roots
+                .iter()
+                .map(|docs_root| DocsRootSummary {
+                    namespace: docs_root.namespace.clone(),
+                    path: docs_root.path.to_string_lossy().to_string(),
+                })
+                .collect(),
             has_local_override: self.has_local_override,
         }
     }
+
+    pub fn resolve_docs_root(&self, doc_id: &str) -> Result<(&DocsRoot, String), String> {
+        resolve_scoped_doc_id(&self.docs_roots, doc_id)
+            .ok_or_else(|| format!("project {} has no docs roots", self.project_id))
+    }
+}
+
+pub fn resolve_scoped_doc_id<'a>(
+    docs_roots: &'a [DocsRoot],
+    doc_id: &str,
+) -> Option<(&'a DocsRoot, String)> {
+    if let Some((namespace, local_doc_id)) = doc_id.split_once(DOC_NAMESPACE_SEPARATOR) {
+        if let Some(docs_root) = docs_roots
+            .iter()
+            .find(|docs_root| docs_root.namespace.as_deref() == Some(namespace))
+        {
+            return Some((docs_root, local_doc_id.to_string()));
+        }
+    }
+
+    docs_roots
+        .first()
+        .map(|docs_root| (docs_root, doc_id.to_string()))
+}
+
+impl Default for WatcherSettings {
+    fn default() -> Self {
+        Self {
+            backend: WatcherBackend::Native,
+            poll_interval: DEFAULT_WATCHER_POLL_INTERVAL,
+        }
+    }
+}
+
+impl DocsRoot {
+    pub fn scoped_doc_id(&self, local_doc_id: &str) -> String {
+        match &self.namespace {
+            Some(namespace) => format!("{namespace}{DOC_NAMESPACE_SEPARATOR}{local_doc_id}"),
+            None => local_doc_id.to_string(),
+        }
+    }
+
+    pub fn scoped_section(&self, section: &str) -> String {
+        match &self.namespace {
+            Some(namespace) if section == "root" => namespace.clone(),
+            Some(namespace) => format!("{namespace}/{section}"),
+            None => section.to_string(),
+        }
+    }
 }
 
 #[cfg(test)]
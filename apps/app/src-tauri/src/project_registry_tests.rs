@@ -1,10 +1,11 @@
 use super::{
     load_project_registry_from_paths, validate_project_removal, validate_project_selection,
+    WatcherBackend, WatcherSettings,
 };
 use crate::project_registration::{build_project_registration_candidate, persist_registered_project};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 fn create_temp_root(suffix: &str) -> PathBuf {
     let timestamp = SystemTime::now()
@@ -167,6 +168,138 @@ path = "{}"
     teardown(&temp_root);
 }
 
+#[test]
+fn resolves_configured_docs_roots_with_namespaces() {
+    let temp_root = create_temp_root("docs-roots");
+    let global_config = temp_root.join("home/.coda/config.toml");
+
+    let workspace_a = create_workspace(&temp_root, "alpha-workspace");
+    let workspace_b = temp_root.join("beta-workspace");
+    fs::create_dir_all(workspace_b.join("handbook")).expect("handbook should be created");
+    fs::create_dir_all(workspace_a.join("adr")).expect("adr should be created");
+    fs::create_dir_all(workspace_a.join("design/rfcs")).expect("rfcs should be created");
+    let local_config = workspace_a.join(".coda/config.toml");
+
+    write_file(
+        &global_config,
+        &format!(
+            r#"
+[projects.beta]
+path = "{}"
+docs_roots = ["handbook"]
+"#,
+            workspace_b.display()
+        ),
+    );
+    write_file(
+        &local_config,
+        r#"
+[project]
+docs_roots = ["docs", "adr", "design/rfcs/"]
+"#,
+    );
+
+    let registry = load_project_registry_from_paths(&workspace_a, &global_config, &local_config)
+        .expect("registry should load configured docs roots");
+
+    let alpha = validate_project_selection(&registry, "alpha-workspace")
+        .expect("current project should exist");
+    assert_eq!(alpha.docs_path, workspace_a.join("docs"));
+    assert_eq!(
+        alpha
+            .docs_roots
+            .iter()
+            .map(|docs_root| docs_root.namespace.as_deref())
+            .collect::<Vec<Option<&str>>>(),
+        vec![None, Some("adr"), Some("design/rfcs")]
+    );
+
+    let (adr_root, adr_doc_id) = alpha
+        .resolve_docs_root("adr:0001-use-markdown.md")
+        .expect("namespaced id should resolve");
+    assert_eq!(adr_root.path, workspace_a.join("adr"));
+    assert_eq!(adr_doc_id, "0001-use-markdown.md");
+    assert_eq!(
+        adr_root.scoped_doc_id(&adr_doc_id),
+        "adr:0001-use-markdown.md"
+    );
+
+    let (primary_root, primary_doc_id) = alpha
+        .resolve_docs_root("plans/active/plan.md")
+        .expect("plain id should resolve");
+    assert_eq!(primary_root.path, workspace_a.join("docs"));
+    assert_eq!(primary_doc_id, "plans/active/plan.md");
+
+    let beta = validate_project_selection(&registry, "beta").expect("beta should exist");
+    assert_eq!(
+        beta.docs_path,
+        workspace_b
+            .join("handbook")
+            .canonicalize()
+            .expect("handbook should canonicalize")
+    );
+
+    write_file(
+        &local_config,
+        r#"
+[project]
+docs_roots = ["docs", "../outside"]
+"#,
+    );
+    let error = load_project_registry_from_paths(&workspace_a, &global_config, &local_config)
+        .expect_err("docs roots outside the project should fail");
+    assert!(error.contains("invalid docs root '../outside'"));
+
+    fs::create_dir_all(workspace_a.join("docs/adr")).expect("nested docs root should be created");
+    for nested_roots in [r#"["docs", "docs/adr"]"#, r#"["docs/adr", "docs"]"#] {
+        write_file(
+            &local_config,
+            &format!("[project]\ndocs_roots = {nested_roots}\n"),
+        );
+        let error = load_project_registry_from_paths(&workspace_a, &global_config, &local_config)
+            .expect_err("nested docs roots should fail");
+        assert!(error.contains("overlaps another docs root"), "{error}");
+    }
+
+    teardown(&temp_root);
+}
+
+#[test]
+fn reads_watcher_settings_from_global_config() {
+    let temp_root = create_temp_root("watcher");
+    let global_config = temp_root.join("home/.coda/config.toml");
+    let workspace = create_workspace(&temp_root, "alpha-workspace");
+    let local_config = workspace.join(".coda/config.toml");
+
+    let registry = load_project_registry_from_paths(&workspace, &global_config, &local_config)
+        .expect("registry should load without watcher config");
+    assert_eq!(registry.watcher_settings, WatcherSettings::default());
+
+    write_file(
+        &global_config,
+        "[watcher]\nbackend = \"poll\"\npoll_interval_ms = 750\n",
+    );
+    let registry = load_project_registry_from_paths(&workspace, &global_config, &local_config)
+        .expect("registry should load with watcher config");
+    assert_eq!(
+        registry.watcher_settings,
+        WatcherSettings {
+            backend: WatcherBackend::Poll,
+            poll_interval: Duration::from_millis(750),
+        }
+    );
+
+    write_file(&global_config, "[watcher]\npoll_interval_ms = 10\n");
+    let registry = load_project_registry_from_paths(&workspace, &global_config, &local_config)
+        .expect("registry should load with a too short poll interval");
+    assert_eq!(
+        registry.watcher_settings.poll_interval,
+        Duration::from_millis(100)
+    );
+
+    teardown(&temp_root);
+}
+
 #[test]
 fn validates_selection_and_removal_contract_messages() {
     let temp_root = create_temp_root("selection-removal");
@@ -200,10 +333,14 @@ path = "{}"
         .expect_err("unknown selection should fail");
     assert!(unknown_error.contains("project selection failed"));
 
-    let removal_error = validate_project_removal(&registry, "alpha", "alpha")
+    let removal_error = validate_project_removal(&registry, &workspace_a, "alpha", "alpha")
         .expect_err("active project removal should fail");
     assert!(removal_error.contains("cannot remove active project 'alpha'"));
 
+    let current_error = validate_project_removal(&registry, &workspace_a, "beta", "alpha")
+        .expect_err("current workspace project removal should fail");
+    assert!(current_error.contains("cannot remove project 'alpha' for the current workspace"));
+
     teardown(&temp_root);
 }
 
@@ -236,13 +373,11 @@ path = "{}"
 
     let registry = load_project_registry_from_paths(&workspace_a, &global_config, &local_config)
         .expect("registry should load");
-    let next_project = build_project_registration_candidate(
-        &registry,
-        &workspace_c.to_string_lossy(),
-    )
-    .expect("candidate should be derived");
+    let next_project =
+        build_project_registration_candidate(&registry, &workspace_c.to_string_lossy(), None)
+            .expect("candidate should be derived");
 
-    persist_registered_project(&global_config, &next_project)
+    persist_registered_project(&global_config, &next_project, None)
         .expect("project should persist to global config");
 
     let config_contents =
@@ -1,19 +1,20 @@
 use crate::docs_watcher::DocsWatcherState;
 use crate::project_registration::{
-    build_project_registration_candidate, persist_registered_project,
+    build_project_registration_candidate, persist_registered_project, remove_registered_project,
 };
 use crate::project_registry::{
-    load_project_registry_from_paths, validate_project_selection, workspace_root_path,
-    ProjectContext, ProjectRegistry, ProjectSummary,
+    load_project_registry_from_paths, validate_project_removal, validate_project_selection,
+    workspace_root_path, ProjectContext, ProjectRegistry, ProjectSummary, WatcherSettings,
 };
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Emitter, State};
 
 const CONFIG_PATH_SEGMENTS: [&str; 2] = [".coda", "config.toml"];
 const ACTIVE_PROJECT_STATE_PATH_SEGMENTS: [&str; 2] = [".coda", "app-state.toml"];
+pub const PROJECTS_CHANGED_EVENT: &str = "projects_changed";
 
 #[derive(Debug, Clone)]
 struct ProjectRuntimeInner {
@@ -30,6 +31,16 @@ pub struct ProjectRegistryState {
     inner: Arc<Mutex<ProjectRuntimeInner>>,
 }
 
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectsChangedPayload {
+    pub added_project_ids: Vec<String>,
+    pub removed_project_ids: Vec<String>,
+    pub updated_project_ids: Vec<String>,
+    pub watcher_settings_changed: bool,
+    pub active_project_id: String,
+}
+
 #[derive(Debug, Default, Deserialize, Serialize)]
 struct ActiveProjectStateFile {
     app: Option<ActiveProjectStateApp>,
@@ -113,6 +124,45 @@ impl ProjectRegistryState {
             .collect())
     }
 
+    pub fn project_contexts(&self) -> Result<Vec<ProjectContext>, String> {
+        let inner = self
+            .inner
+            .lock()
+            .map_err(|_| "project runtime state lock poisoned".to_string())?;
+
+        Ok(inner.registry.projects.clone())
+    }
+
+    pub fn project_context(&self, project_id: &str) -> Result<ProjectContext, String> {
+        let inner = self
+            .inner
+            .lock()
+            .map_err(|_| "project runtime state lock poisoned".to_string())?;
+
+        validate_project_selection(&inner.registry, project_id)
+    }
+
+    pub fn watcher_settings(&self) -> Result<WatcherSettings, String> {
+        let inner = self
+            .inner
+            .lock()
+            .map_err(|_| "project runtime state lock poisoned".to_string())?;
+
+        Ok(inner.registry.watcher_settings)
+    }
+
+    pub fn config_paths(&self) -> Result<Vec<PathBuf>, String> {
+        let inner = self
+            .inner
+            .lock()
+            .map_err(|_| "project runtime state lock poisoned".to_string())?;
+
+        Ok(vec![
+            inner.global_config_path.clone(),
+            inner.local_config_path.clone(),
+        ])
+    }
+
     pub fn active_project_summary(&self) -> Result<ProjectSummary, String> {
         Ok(self.active_project_context()?.to_summary())
     }
@@ -126,6 +176,13 @@ impl ProjectRegistryState {
         validate_project_selection(&inner.registry, &inner.active_project_id)
     }
 
+    pub fn is_active_project(&self, project_id: &str) -> bool {
+        self.inner
+            .lock()
+            .map(|inner| inner.active_project_id == project_id)
+            .unwrap_or(false)
+    }
+
     pub fn set_active_project_by_id(&self, project_id: &str) -> Result<ProjectSummary, String> {
         let (next_project, active_state_path) = {
             let mut inner = self
@@ -142,14 +199,19 @@ impl ProjectRegistryState {
         Ok(next_project.to_summary())
     }
 
-    pub fn register_project_by_root_path(&self, root_path: &str) -> Result<ProjectSummary, String> {
+    pub fn register_project_by_root_path(
+        &self,
+        root_path: &str,
+        docs_roots: Option<&[String]>,
+    ) -> Result<ProjectSummary, String> {
         let mut inner = self
             .inner
             .lock()
             .map_err(|_| "project runtime state lock poisoned".to_string())?;
 
-        let next_project = build_project_registration_candidate(&inner.registry, root_path)?;
-        persist_registered_project(&inner.global_config_path, &next_project)?;
+        let next_project =
+            build_project_registration_candidate(&inner.registry, root_path, docs_roots)?;
+        persist_registered_project(&inner.global_config_path, &next_project, docs_roots)?;
 
         let active_project_id = inner.active_project_id.clone();
         let reloaded_registry = load_project_registry_from_paths(
@@ -165,6 +227,117 @@ impl ProjectRegistryState {
         validate_project_selection(&inner.registry, &next_project.project_id)
             .map(|project| project.to_summary())
     }
+
+    pub fn reload_registry(&self) -> Result<ProjectsChangedPayload, String> {
+        let mut inner = self
+            .inner
+            .lock()
+            .map_err(|_| "project runtime state lock poisoned".to_string())?;
+
+        inner.reload_registry()
+    }
+
+    pub fn unregister_project_by_id(
+        &self,
+        project_id: &str,
+    ) -> Result<ProjectsChangedPayload, String> {
+        let mut inner = self
+            .inner
+            .lock()
+            .map_err(|_| "project runtime state lock poisoned".to_string())?;
+
+        validate_project_removal(
+            &inner.registry,
+            &inner.current_workspace_root,
+            &inner.active_project_id,
+            project_id,
+        )?;
+        remove_registered_project(&inner.global_config_path, project_id)?;
+
+        inner.reload_registry()
+    }
+}
+
+impl ProjectRuntimeInner {
+    fn reload_registry(&mut self) -> Result<ProjectsChangedPayload, String> {
+        let reloaded_registry = load_project_registry_from_paths(
+            &self.current_workspace_root,
+            &self.global_config_path,
+            &self.local_config_path,
+        )?;
+        if reloaded_registry.projects.is_empty() {
+            return Err(
+                "project registry reload failed: config has no projects; keeping the current registry"
+                    .to_string(),
+            );
+        }
+
+        let mut changes = diff_project_registries(&self.registry, &reloaded_registry);
+        let active_project_id = self.active_project_id.clone();
+        self.active_project_id =
+            resolve_initial_active_project_id(&reloaded_registry, Some(active_project_id));
+        self.registry = reloaded_registry;
+        changes.active_project_id = self.active_project_id.clone();
+
+        Ok(changes)
+    }
+}
+
+impl ProjectsChangedPayload {
+    pub fn is_empty(&self) -> bool {
+        self.added_project_ids.is_empty()
+            && self.removed_project_ids.is_empty()
+            && self.updated_project_ids.is_empty()
+            && !self.watcher_settings_changed
+    }
+}
+
+pub fn reload_projects(
+    app_handle: &AppHandle,
+    registry_state: &ProjectRegistryState,
+    watcher_state: &DocsWatcherState,
+) -> Result<ProjectsChangedPayload, String> {
+    let changes = registry_state.reload_registry()?;
+    if !changes.is_empty() {
+        apply_project_changes(app_handle, registry_state, watcher_state, &changes)?;
+    }
+
+    Ok(changes)
+}
+
+fn apply_project_changes(
+    app_handle: &AppHandle,
+    registry_state: &ProjectRegistryState,
+    watcher_state: &DocsWatcherState,
+    changes: &ProjectsChangedPayload,
+) -> Result<(), String> {
+    for project_id in changes
+        .removed_project_ids
+        .iter()
+        .chain(&changes.updated_project_ids)
+    {
+        watcher_state.unwatch_project(project_id)?;
+    }
+    for project_id in changes
+        .added_project_ids
+        .iter()
+        .chain(&changes.updated_project_ids)
+    {
+        let project = registry_state.project_context(project_id)?;
+        if let Err(error) = watcher_state.watch_project(app_handle.clone(), &project) {
+            log::warn!("reloaded project {project_id} without docs watching: {error}");
+        }
+    }
+    let active_project = registry_state.active_project_context()?;
+    watcher_state.switch_to_project(app_handle.clone(), &active_project)?;
+    if changes.watcher_settings_changed {
+        watcher_state.configure(registry_state.watcher_settings()?)?;
+        watcher_state.restart(app_handle.clone())?;
+    }
+
+    app_handle
+        .emit(PROJECTS_CHANGED_EVENT, changes.clone())
+        .map_err(|error| format!("project registry reload failed: cannot emit changes: {error}"))
 }
 
 #[tauri::command]
@@ -197,9 +370,64 @@ pub fn set_active_project(
 #[tauri::command]
 pub fn register_project(
     root_path: String,
+    docs_roots: Option<Vec<String>>,
     state: State<'_, ProjectRegistryState>,
+    watcher_state: State<'_, DocsWatcherState>,
+    app_handle: AppHandle,
 ) -> Result<ProjectSummary, String> {
-    state.register_project_by_root_path(&root_path)
+    let registered_project =
+        state.register_project_by_root_path(&root_path, docs_roots.as_deref())?;
+    let project = state.project_context(&registered_project.project_id)?;
+    if let Err(error) = watcher_state.watch_project(app_handle, &project) {
+        log::warn!(
+            "registered project {} without docs watching: {error}",
+            project.project_id
+        );
+    }
+
+    Ok(registered_project)
+}
+
+#[tauri::command]
+pub fn unregister_project(
+    project_id: String,
+    state: State<'_, ProjectRegistryState>,
+    watcher_state: State<'_, DocsWatcherState>,
+    app_handle: AppHandle,
+) -> Result<ProjectsChangedPayload, String> {
+    let changes = state.unregister_project_by_id(&project_id)?;
+    apply_project_changes(&app_handle, &state, &watcher_state, &changes)?;
+    Ok(changes)
+}
+
+fn diff_project_registries(
+    previous: &ProjectRegistry,
+    next: &ProjectRegistry,
+) -> ProjectsChangedPayload {
+    let mut changes = ProjectsChangedPayload::default();
+
+    for project in &next.projects {
+        match previous
+            .projects
+            .iter()
+            .find(|previous_project| previous_project.project_id == project.project_id)
+        {
+            None => changes.added_project_ids.push(project.project_id.clone()),
+            Some(previous_project) if previous_project != project => {
+                changes.updated_project_ids.push(project.project_id.clone())
+            }
+            Some(_) => {}
+        }
+    }
+    changes.removed_project_ids = previous
+        .projects
+        .iter()
+        .filter(|project| !has_project_id(next, &project.project_id))
+        .map(|project| project.project_id.clone())
+        .collect();
+    changes.watcher_settings_changed = previous.watcher_settings != next.watcher_settings;
+
+    changes
 }
 
 fn resolve_initial_active_project_id(
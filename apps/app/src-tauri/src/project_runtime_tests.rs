@@ -1,7 +1,8 @@
 use super::ProjectRegistryState;
+use crate::project_registry::{WatcherBackend, WatcherSettings};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 fn create_temp_root(suffix: &str) -> PathBuf {
     let timestamp = SystemTime::now()
@@ -219,7 +220,7 @@ path = "{}"
     .expect("runtime state should load");
 
     let registered = state
-        .register_project_by_root_path(&workspace_b.to_string_lossy())
+        .register_project_by_root_path(&workspace_b.to_string_lossy(), None)
         .expect("project registration should succeed");
     assert_eq!(registered.project_id, "workspace-b");
 
@@ -280,7 +281,7 @@ path = "{}"
             .expect("runtime state should load");
 
     let error = state
-        .register_project_by_root_path(&workspace.to_string_lossy())
+        .register_project_by_root_path(&workspace.to_string_lossy(), None)
         .expect_err("duplicate root path should fail");
     assert!(error.contains("duplicate root path"));
 
@@ -316,9 +317,200 @@ path = "{}"
             .expect("runtime state should load");
 
     let error = state
-        .register_project_by_root_path(&missing_docs_workspace.to_string_lossy())
+        .register_project_by_root_path(&missing_docs_workspace.to_string_lossy(), None)
         .expect_err("workspace without docs should fail");
     assert!(error.contains("docs directory does not exist under root path"));
 
     teardown(&temp_root);
 }
+
+#[test]
+fn reloads_registry_and_reports_project_changes() {
+    let temp_root = create_temp_root("reload-registry");
+    let home_root = temp_root.join("home");
+    let global_config = home_root.join(".coda/config.toml");
+    let active_state = home_root.join(".coda/app-state.toml");
+
+    let workspace_a = create_workspace_with_doc(&temp_root, "workspace-a", "a.md");
+    let workspace_b = create_workspace_with_doc(&temp_root, "workspace-b", "b.md");
+    let workspace_c = create_workspace_with_doc(&temp_root, "workspace-c", "c.md");
+    let local_config = workspace_a.join(".coda/config.toml");
+
+    write_file(
+        &global_config,
+        &format!(
+            r#"
+[projects.alpha]
+path = "{}"
+
+[projects.beta]
+path = "{}"
+"#,
+            workspace_a.display(),
+            workspace_b.display()
+        ),
+    );
+    write_file(&local_config, "");
+
+    let state = ProjectRegistryState::from_paths(
+        &workspace_a,
+        &global_config,
+        &local_config,
+        &active_state,
+    )
+    .expect("runtime state should load");
+    state
+        .set_active_project_by_id("alpha")
+        .expect("alpha should be selectable");
+
+    write_file(
+        &global_config,
+        &format!(
+            r#"
+[projects.alpha]
+path = "{}"
+display_name = "Alpha Renamed"
+
+[projects.gamma]
+path = "{}"
+"#,
+            workspace_a.display(),
+            workspace_c.display()
+        ),
+    );
+
+    let changes = state.reload_registry().expect("registry should reload");
+    assert_eq!(changes.added_project_ids, vec!["gamma".to_string()]);
+    assert_eq!(changes.removed_project_ids, vec!["beta".to_string()]);
+    assert_eq!(changes.updated_project_ids, vec!["alpha".to_string()]);
+    assert_eq!(changes.active_project_id, "alpha");
+
+    let unchanged = state.reload_registry().expect("registry should reload");
+    assert!(unchanged.is_empty());
+    assert_eq!(
+        state
+            .active_project_summary()
+            .expect("active project should resolve")
+            .display_name,
+        "Alpha Renamed"
+    );
+
+    teardown(&temp_root);
+}
+
+#[test]
+fn reports_watcher_settings_changes_on_reload() {
+    let temp_root = create_temp_root("reload-watcher-settings");
+    let home_root = temp_root.join("home");
+    let global_config = home_root.join(".coda/config.toml");
+    let active_state = home_root.join(".coda/app-state.toml");
+
+    let workspace = create_workspace_with_doc(&temp_root, "workspace-a", "a.md");
+    let local_config = workspace.join(".coda/config.toml");
+    let projects_config = format!("[projects.alpha]\npath = \"{}\"\n", workspace.display());
+    write_file(&global_config, &projects_config);
+    write_file(&local_config, "");
+
+    let state =
+        ProjectRegistryState::from_paths(&workspace, &global_config, &local_config, &active_state)
+            .expect("runtime state should load");
+    assert_eq!(
+        state.watcher_settings().expect("settings should load"),
+        WatcherSettings::default()
+    );
+
+    write_file(
+        &global_config,
+        &format!("{projects_config}\n[watcher]\nbackend = \"poll\"\npoll_interval_ms = 500\n"),
+    );
+    let changes = state.reload_registry().expect("registry should reload");
+    assert!(changes.watcher_settings_changed);
+    assert!(!changes.is_empty());
+    assert!(changes.updated_project_ids.is_empty());
+    assert_eq!(
+        state.watcher_settings().expect("settings should load"),
+        WatcherSettings {
+            backend: WatcherBackend::Poll,
+            poll_interval: Duration::from_millis(500),
+        }
+    );
+
+    let unchanged = state.reload_registry().expect("registry should reload");
+    assert!(!unchanged.watcher_settings_changed);
+    assert!(unchanged.is_empty());
+
+    teardown(&temp_root);
+}
+
+#[test]
+fn unregisters_project_and_preserves_unknown_config_keys() {
+    let temp_root = create_temp_root("unregister-project");
+    let home_root = temp_root.join("home");
+    let global_config = home_root.join(".coda/config.toml");
+    let active_state = home_root.join(".coda/app-state.toml");
+
+    let workspace_a = create_workspace_with_doc(&temp_root, "workspace-a", "a.md");
+    let workspace_b = create_workspace_with_doc(&temp_root, "workspace-b", "b.md");
+    let local_config = workspace_a.join(".coda/config.toml");
+
+    write_file(
+        &global_config,
+        &format!(
+            r#"
+[ui]
+theme = "dark"
+
+[projects.alpha]
+path = "{}"
+pinned = true
+
+[projects.beta]
+path = "{}"
+"#,
+            workspace_a.display(),
+            workspace_b.display()
+        ),
+    );
+    write_file(&local_config, "");
+
+    let state = ProjectRegistryState::from_paths(
+        &workspace_a,
+        &global_config,
+        &local_config,
+        &active_state,
+    )
+    .expect("runtime state should load");
+    state
+        .set_active_project_by_id("alpha")
+        .expect("alpha should be selectable");
+
+    let active_error = state
+        .unregister_project_by_id("alpha")
+        .expect_err("active project removal should fail");
+    assert!(active_error.contains("cannot remove active project 'alpha'"));
+
+    let changes = state
+        .unregister_project_by_id("beta")
+        .expect("background project removal should succeed");
+    assert_eq!(changes.removed_project_ids, vec!["beta".to_string()]);
+    assert_eq!(changes.active_project_id, "alpha");
+
+    let summaries = state
+        .list_project_summaries()
+        .expect("summaries should be available");
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].project_id, "alpha");
+
+    let config_contents =
+        fs::read_to_string(&global_config).expect("global config should be readable");
+    assert!(!config_contents.contains("[projects.beta]"));
+    assert!(config_contents.contains("pinned = true"));
+    assert!(config_contents.contains("theme = \"dark\""));
+
+    let last_error = state
+        .unregister_project_by_id("alpha")
+        .expect_err("last project removal should fail");
+    assert!(last_error.contains("cannot remove the last registered project"));
+
+    teardown(&temp_root);
+}
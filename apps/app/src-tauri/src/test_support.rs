@@ -0,0 +1,34 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static TEMP_WORKSPACE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub fn create_temp_workspace(name: &str) -> PathBuf {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after unix epoch")
+        .as_nanos();
+    let workspace_path = std::env::temp_dir().join(format!(
+        "coda-{name}-tests-{}-{}-{timestamp}",
+        std::process::id(),
+        TEMP_WORKSPACE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::create_dir_all(workspace_path.join("docs")).expect("temp workspace should be created");
+
+    workspace_path
+        .canonicalize()
+        .expect("temp workspace should canonicalize")
+}
+
+pub fn write_doc_file(path: &Path, contents: &str) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("parent directory should be created");
+    }
+    fs::write(path, contents).expect("document file should be written");
+}
+
+pub fn teardown_workspace(path: &Path) {
+    fs::remove_dir_all(path).expect("temp workspace should be removed");
+}